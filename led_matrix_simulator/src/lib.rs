@@ -0,0 +1,54 @@
+/*
+MIT License
+
+Copyright (c) 2026 Vincent Hiribarren
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Simulator of a RGB LED matrix panel, to run the drawing code of the
+//! nrf52833-rgb-led-matrix lib on the computer.
+//!
+//! A [`Simulator`] drives the pins of a [`Recorder`] with the [`LedMatrix`] of
+//! the lib, line after line and bit plane after bit plane, as the scheduler of
+//! the micro:bit does. The time each LED is lit in the recording gives the
+//! [`LedFrame`] seen on the panel, which is saved as a PNG file, an animated
+//! GIF, or printed in the terminal:
+//!
+//! ```no_run
+//! use led_matrix_simulator::{DotStyle, Simulator};
+//! use nrf52833_rgb_led_matrix::canvas::{Canvas, Color};
+//!
+//! let mut canvas = Canvas::<64, 32>::new();
+//! canvas.draw_rectangle(4, 4, 16, 8, Color::new(255, 128, 0));
+//! let mut simulator = Simulator::<4, 64, 32>::new();
+//! simulator.set_bcm_planes_nb(4);
+//! let frame = simulator.render(&canvas);
+//! frame.save_png("panel.png", &DotStyle::default()).unwrap();
+//! print!("{}", frame.to_ansi());
+//! ```
+//!
+//! [`LedMatrix`]: nrf52833_rgb_led_matrix::ledmatrix::LedMatrix
+//! [`Recorder`]: nrf52833_rgb_led_matrix::recorder::Recorder
+
+mod output;
+mod panel;
+
+pub use output::{save_gif, DotStyle};
+pub use panel::{LedFrame, SimulatedLedMatrix, Simulator, DEFAULT_BCM_PLANES_NB};
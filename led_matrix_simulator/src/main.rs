@@ -0,0 +1,183 @@
+/*
+MIT License
+
+Copyright (c) 2026 Vincent Hiribarren
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+use std::error::Error;
+use std::path::PathBuf;
+
+use clap::error::ErrorKind;
+use clap::{CommandFactory, Parser, ValueEnum};
+use led_matrix_simulator::{save_gif, DotStyle, LedFrame, Simulator, DEFAULT_BCM_PLANES_NB};
+use nrf52833_rgb_led_matrix::canvas::{Canvas, Color, TextOptions};
+use nrf52833_rgb_led_matrix::fonts::Font5x7;
+use nrf52833_rgb_led_matrix::gamma::ColorCorrection;
+
+#[derive(Clone, ValueEnum)]
+enum Scene {
+    Colors,
+    Numbers,
+    HelloWorld,
+}
+
+#[derive(Clone, ValueEnum, PartialEq)]
+enum Format {
+    Ansi,
+    Png,
+    Gif,
+}
+
+#[derive(Parser)]
+#[command(author, version, about, long_about)]
+/// Simulator of a 64x32 RGB LED matrix panel driven by the
+/// nrf52833-rgb-led-matrix lib
+///
+/// Draw the scene of one of the examples of the lib, and display it as it is
+/// seen on the panel, with the same bit planes and brightness.
+///
+/// The ansi and png formats only show the first canvas of animated scenes.
+struct Cli {
+    /// Drawing of one of the examples of the lib
+    #[arg(value_enum, default_value_t = Scene::HelloWorld)]
+    scene: Scene,
+    /// Text with ANSI colors to the standard output, or image files
+    #[arg(value_enum, short, long, default_value_t = Format::Ansi)]
+    format: Format,
+    /// Required when output format is png or gif
+    #[arg(short, long)]
+    output_file: Option<PathBuf>,
+    /// Number of displayed bit planes, as set with `set_bcm_planes_nb`
+    #[arg(short, long, default_value_t = DEFAULT_BCM_PLANES_NB)]
+    bcm_planes: u8,
+    /// Part of each bit plane during which the LEDs are on, as set with `set_brightness`
+    #[arg(long, default_value_t = u8::MAX)]
+    brightness: u8,
+    /// Gamma of the color correction, none by default
+    #[arg(short, long)]
+    gamma: Option<f32>,
+    /// Distance between two LEDs in the images, in pixels
+    #[arg(long, default_value_t = 8, value_parser = clap::value_parser!(u32).range(1..))]
+    pitch: u32,
+    /// Display duration of each canvas of the GIF
+    #[arg(long, default_value_t = 2000)]
+    delay_ms: u32,
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let args = Cli::parse();
+    if args.format != Format::Ansi && args.output_file.is_none() {
+        let mut cmd = Cli::command();
+        cmd.error(
+            ErrorKind::ArgumentConflict,
+            "When format is png or gif, an output file must be provided",
+        )
+        .exit()
+    }
+
+    let mut simulator = Simulator::<4, 64, 32>::new();
+    simulator.set_bcm_planes_nb(args.bcm_planes);
+    simulator.set_brightness(args.brightness);
+    if let Some(gamma) = args.gamma {
+        simulator
+            .led_matrix_mut()
+            .set_color_correction(ColorCorrection::from_gamma(gamma));
+    }
+    let frames: Vec<LedFrame> = scene_canvases(&args.scene)
+        .iter()
+        .map(|canvas| simulator.render(canvas))
+        .collect();
+
+    let style = DotStyle {
+        pitch: args.pitch,
+        dot_diameter: args.pitch as f32 * 0.75,
+        ..Default::default()
+    };
+    match (args.format, args.output_file) {
+        (Format::Png, Some(file)) => frames[0].save_png(file, &style)?,
+        (Format::Gif, Some(file)) => save_gif(file, &frames, &style, args.delay_ms)?,
+        _ => print!("{}", frames[0].to_ansi()),
+    }
+    Ok(())
+}
+
+fn scene_canvases(scene: &Scene) -> Vec<Canvas<64, 32>> {
+    match scene {
+        Scene::Colors => vec![colors()],
+        Scene::Numbers => {
+            let mut canvas = Canvas::new();
+            canvas.draw_text(0, 0, "0123456789", Font5x7, Default::default());
+            vec![canvas]
+        }
+        Scene::HelloWorld => [("HELLO", Color::RED), ("WORLD", Color::BLUE)]
+            .into_iter()
+            .map(|(text, color)| {
+                let mut canvas = Canvas::new();
+                canvas.draw_text(
+                    1,
+                    1,
+                    text,
+                    Font5x7,
+                    TextOptions {
+                        color,
+                        ..Default::default()
+                    },
+                );
+                canvas
+            })
+            .collect(),
+    }
+}
+
+/// Same drawing as the `colors` example
+fn colors() -> Canvas<64, 32> {
+    let mut canvas = Canvas::new();
+    let w = canvas.width();
+    let h = canvas.height();
+    let color_segment = w / 6;
+    let ramp_color = |pos: usize| -> u8 { (255 * pos / color_segment) as u8 };
+    for y in 0..h {
+        for x in 0..w {
+            let coeff_y = (h - y - 1) as f32 / (h - 1) as f32;
+            canvas.0[y][x] = coeff_y
+                * match x {
+                    x if x <= color_segment => Color::new(255, ramp_color(x), 0),
+                    x if x <= 2 * color_segment => {
+                        Color::new(ramp_color(2 * color_segment - x), 255, 0)
+                    }
+                    x if x <= 3 * color_segment => {
+                        Color::new(0, 255, ramp_color(x - 2 * color_segment))
+                    }
+                    x if x <= 4 * color_segment => {
+                        Color::new(0, ramp_color(4 * color_segment - x), 255)
+                    }
+                    x if x <= 5 * color_segment => {
+                        Color::new(ramp_color(x - 4 * color_segment), 0, 255)
+                    }
+                    x if x <= 6 * color_segment => {
+                        Color::new(255, 0, ramp_color(6 * color_segment - x))
+                    }
+                    _ => Color::new(255, 0, 0),
+                };
+        }
+    }
+    canvas
+}
@@ -0,0 +1,166 @@
+/*
+MIT License
+
+Copyright (c) 2026 Vincent Hiribarren
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Images of the simulated panel: PNG files, animated GIFs, and ANSI true color
+//! text for the terminal.
+
+use std::fmt::Write;
+use std::fs::File;
+use std::path::Path;
+
+use image::codecs::gif::{GifEncoder, Repeat};
+use image::{Delay, DynamicImage, Frame, ImageResult, Rgb, RgbImage};
+
+use crate::panel::LedFrame;
+
+/// Dark grey, to still see the grid of the panel
+const OFF_COLOR: [u8; 3] = [24, 24, 24];
+
+/// Look of the LEDs in the images: round dots, on the dark panel
+#[derive(Copy, Clone)]
+pub struct DotStyle {
+    /// Distance between two LEDs, in image pixels
+    pub pitch: u32,
+    /// Diameter of a LED, in image pixels
+    pub dot_diameter: f32,
+    /// Color of a LED which is off
+    pub off_color: [u8; 3],
+    pub background: [u8; 3],
+}
+
+impl Default for DotStyle {
+    fn default() -> Self {
+        DotStyle {
+            pitch: 8,
+            dot_diameter: 6.0,
+            off_color: OFF_COLOR,
+            background: [0, 0, 0],
+        }
+    }
+}
+
+impl DotStyle {
+    /// Part of the pixel at `(dx, dy)` of a LED cell covered by the dot, with
+    /// antialiased borders.
+    fn coverage(&self, dx: u32, dy: u32) -> f32 {
+        let center = self.pitch as f32 / 2.0;
+        let distance = (dx as f32 + 0.5 - center).hypot(dy as f32 + 0.5 - center);
+        (self.dot_diameter / 2.0 + 0.5 - distance).clamp(0.0, 1.0)
+    }
+}
+
+impl LedFrame {
+    /// Image of the panel, each LED being a dot of `style`
+    pub fn to_image(&self, style: &DotStyle) -> RgbImage {
+        let pitch = style.pitch;
+        RgbImage::from_fn(
+            self.width() as u32 * pitch,
+            self.height() as u32 * pitch,
+            |x, y| {
+                let led = self.led((x / pitch) as usize, (y / pitch) as usize);
+                let color = if led == [0, 0, 0] {
+                    style.off_color
+                } else {
+                    led
+                };
+                let coverage = style.coverage(x % pitch, y % pitch);
+                Rgb(std::array::from_fn(|channel| {
+                    let background = style.background[channel] as f32;
+                    (background + (color[channel] as f32 - background) * coverage).round() as u8
+                }))
+            },
+        )
+    }
+
+    pub fn save_png(&self, path: impl AsRef<Path>, style: &DotStyle) -> ImageResult<()> {
+        self.to_image(style)
+            .save_with_format(path, image::ImageFormat::Png)
+    }
+
+    /// ANSI true color text, a LED being a `●` followed by a space, which is
+    /// about a square in most terminal fonts.
+    pub fn to_ansi(&self) -> String {
+        let mut text = String::new();
+        for y in 0..self.height() {
+            for x in 0..self.width() {
+                let [r, g, b] = match self.led(x, y) {
+                    [0, 0, 0] => OFF_COLOR,
+                    led => led,
+                };
+                write!(text, "\x1b[38;2;{r};{g};{b}m● ").unwrap();
+            }
+            text.push_str("\x1b[0m\n");
+        }
+        text
+    }
+}
+
+/// Animated GIF of `frames`, looping forever, each frame being shown `frame_delay_ms`.
+pub fn save_gif(
+    path: impl AsRef<Path>,
+    frames: &[LedFrame],
+    style: &DotStyle,
+    frame_delay_ms: u32,
+) -> ImageResult<()> {
+    let mut encoder = GifEncoder::new(File::create(path)?);
+    encoder.set_repeat(Repeat::Infinite)?;
+    let delay = Delay::from_numer_denom_ms(frame_delay_ms, 1);
+    encoder.encode_frames(frames.iter().map(|frame| {
+        let image = DynamicImage::ImageRgb8(frame.to_image(style)).into_rgba8();
+        Frame::from_parts(image, 0, 0, delay)
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::panel::Simulator;
+    use nrf52833_rgb_led_matrix::canvas::{Canvas, Color};
+
+    fn test_frame() -> LedFrame {
+        let mut canvas = Canvas::<4, 2>::new();
+        canvas.draw_pixel(1, 0, Color::RED);
+        Simulator::<1, 4, 2>::new().render(&canvas)
+    }
+
+    #[test]
+    fn image_has_a_dot_per_led() {
+        let style = DotStyle::default();
+        let image = test_frame().to_image(&style);
+        assert_eq!(image.dimensions(), (32, 16));
+        // Center and corner of the cells
+        assert_eq!(image.get_pixel(12, 4), &Rgb([255, 0, 0]));
+        assert_eq!(image.get_pixel(4, 4), &Rgb(OFF_COLOR));
+        assert_eq!(image.get_pixel(8, 0), &Rgb(style.background));
+    }
+
+    #[test]
+    fn ansi_has_a_line_per_row() {
+        let text = test_frame().to_ansi();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("\x1b[38;2;24;24;24m● \x1b[38;2;255;0;0m● "));
+        assert!(lines.iter().all(|line| line.ends_with("\x1b[0m")));
+    }
+}
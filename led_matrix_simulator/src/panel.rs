@@ -0,0 +1,244 @@
+/*
+MIT License
+
+Copyright (c) 2026 Vincent Hiribarren
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Simulated panel, replaying the signals of the [`LedMatrix`] of the lib
+//! recorded by its [`Recorder`].
+
+use nrf52833_rgb_led_matrix::bitplane::BitPlaneFrame;
+use nrf52833_rgb_led_matrix::canvas::Canvas;
+use nrf52833_rgb_led_matrix::ledmatrix::{ColorBitPosition, LedMatrix};
+use nrf52833_rgb_led_matrix::recorder::{Recorder, RecordingPin, RecordingPort};
+
+/// Bit planes displayed unless changed, all of them as with the scheduler
+pub const DEFAULT_BCM_PLANES_NB: u8 = ColorBitPosition::MSB_POSITION + 1;
+
+/// Display time of the least significant displayed bit plane, the pin changes
+/// being instantaneous
+const BCM_BASE_PERIOD_NS: u64 = 1;
+
+/// [`LedMatrix`] driving the simulated panel
+pub type SimulatedLedMatrix<
+    const LINECTRL_PIN_COUNT: usize,
+    const WIDTH: usize,
+    const HEIGHT: usize,
+> = LedMatrix<LINECTRL_PIN_COUNT, WIDTH, HEIGHT, RecordingPort, RecordingPin>;
+
+/// Colors of the LEDs, as seen on the panel
+#[derive(Clone, Debug, PartialEq)]
+pub struct LedFrame {
+    width: usize,
+    height: usize,
+    leds: Vec<[u8; 3]>,
+}
+
+impl LedFrame {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Red, green and blue of the LED at `(x, y)`
+    pub fn led(&self, x: usize, y: usize) -> [u8; 3] {
+        self.leds[y * self.width + x]
+    }
+}
+
+/// Panel of `WIDTH` x `HEIGHT` LEDs, with `LINECTRL_PIN_COUNT` address lines
+pub struct Simulator<const LINECTRL_PIN_COUNT: usize, const WIDTH: usize, const HEIGHT: usize> {
+    led_matrix: SimulatedLedMatrix<LINECTRL_PIN_COUNT, WIDTH, HEIGHT>,
+    recorder: Recorder,
+    frame: BitPlaneFrame<WIDTH, HEIGHT>,
+    bcm_planes_nb: u8,
+    brightness: u8,
+}
+
+impl<const LINECTRL_PIN_COUNT: usize, const WIDTH: usize, const HEIGHT: usize> Default
+    for Simulator<LINECTRL_PIN_COUNT, WIDTH, HEIGHT>
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const LINECTRL_PIN_COUNT: usize, const WIDTH: usize, const HEIGHT: usize>
+    Simulator<LINECTRL_PIN_COUNT, WIDTH, HEIGHT>
+{
+    pub fn new() -> Self {
+        let recorder = Recorder::with_step_ns(0);
+        Simulator {
+            led_matrix: recorder.led_matrix(),
+            recorder,
+            frame: BitPlaneFrame::new(),
+            bcm_planes_nb: DEFAULT_BCM_PLANES_NB,
+            brightness: u8::MAX,
+        }
+    }
+
+    /// To set the color correction and the scan mapping, as on the micro:bit
+    pub fn led_matrix_mut(&mut self) -> &mut SimulatedLedMatrix<LINECTRL_PIN_COUNT, WIDTH, HEIGHT> {
+        &mut self.led_matrix
+    }
+
+    /// Same as the scheduler: clamped between 1 and 8, only the most
+    /// significant bits being displayed.
+    pub fn set_bcm_planes_nb(&mut self, bcm_planes_nb: u8) {
+        self.bcm_planes_nb = bcm_planes_nb.clamp(1, ColorBitPosition::MSB_POSITION + 1);
+    }
+
+    pub fn bcm_planes_nb(&self) -> u8 {
+        self.bcm_planes_nb
+    }
+
+    /// Same as the scheduler: part of each BCM slot during which the LEDs are on
+    pub fn set_brightness(&mut self, brightness: u8) {
+        self.brightness = brightness;
+    }
+
+    pub fn brightness(&self) -> u8 {
+        self.brightness
+    }
+
+    /// Display a whole frame of `canvas`, and give the colors of the LEDs,
+    /// averaged over the frame.
+    pub fn render(&mut self, canvas: &Canvas<WIDTH, HEIGHT>) -> LedFrame {
+        let scan_mapping = self.led_matrix.scan_mapping();
+        self.frame
+            .update_from_canvas(canvas, self.led_matrix.color_correction(), scan_mapping);
+        self.recorder.restart();
+
+        // Only the `bcm_planes_nb` most significant bits are displayed
+        let lowest_bit_position = ColorBitPosition::MSB_POSITION + 1 - self.bcm_planes_nb;
+        for line in 0..scan_mapping.scan_lines() {
+            for cycle in 0..self.bcm_planes_nb {
+                self.led_matrix
+                    .draw_frame_line(
+                        &self.frame,
+                        line,
+                        ColorBitPosition(lowest_bit_position + cycle),
+                    )
+                    .expect("Simulated pins cannot fail");
+                self.recorder.advance_ns(BCM_BASE_PERIOD_NS << cycle);
+            }
+        }
+
+        // The LEDs are on during `brightness` / 255 of each slot
+        let leds = self
+            .recorder
+            .decode_frame(scan_mapping)
+            .0
+            .iter()
+            .flatten()
+            .map(|color| {
+                [color.r(), color.g(), color.b()].map(|channel| {
+                    ((channel as u32 * self.brightness as u32 + u8::MAX as u32 / 2)
+                        / u8::MAX as u32) as u8
+                })
+            })
+            .collect();
+        LedFrame {
+            width: WIDTH,
+            height: HEIGHT,
+            leds,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nrf52833_rgb_led_matrix::canvas::Color;
+    use nrf52833_rgb_led_matrix::scan::StripedScan;
+    use nrf52833_rgb_led_matrix::test_util::gradient_canvas;
+
+    fn assert_same_colors(frame: &LedFrame, canvas: &Canvas<32, 16>) {
+        for (y, row) in canvas.0.iter().enumerate() {
+            for (x, color) in row.iter().enumerate() {
+                assert_eq!(
+                    frame.led(x, y),
+                    [color.r(), color.g(), color.b()],
+                    "({x}, {y})"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn all_bit_planes_show_the_canvas_colors() {
+        let canvas = gradient_canvas::<32, 16>();
+        let frame = Simulator::<3, 32, 16>::new().render(&canvas);
+        assert_eq!((frame.width(), frame.height()), (32, 16));
+        assert_same_colors(&frame, &canvas);
+    }
+
+    #[test]
+    fn scan_mapping_is_undone_by_the_panel() {
+        static MAPPING: StripedScan = StripedScan {
+            scan_lines: 4,
+            stripe_width: 8,
+            bottom_row_first: true,
+            zigzag: true,
+        };
+        let canvas = gradient_canvas::<32, 16>();
+        let mut simulator = Simulator::<3, 32, 16>::new();
+        simulator.led_matrix_mut().set_scan_mapping(&MAPPING);
+        assert_same_colors(&simulator.render(&canvas), &canvas);
+    }
+
+    #[test]
+    fn only_the_most_significant_bits_are_displayed() {
+        let mut canvas = Canvas::<32, 16>::new();
+        canvas
+            .draw_pixel(0, 0, Color::new(0x0F, 0xF0, 0x80))
+            .draw_pixel(1, 0, Color::WHITE);
+        let mut simulator = Simulator::<3, 32, 16>::new();
+        simulator.set_bcm_planes_nb(4);
+        let frame = simulator.render(&canvas);
+        assert_eq!(frame.led(0, 0), [0x00, 0xFF, 0x88]);
+        assert_eq!(frame.led(1, 0), [0xFF, 0xFF, 0xFF]);
+        simulator.set_bcm_planes_nb(0);
+        assert_eq!(simulator.bcm_planes_nb(), 1);
+        assert_eq!(simulator.render(&canvas).led(0, 0), [0x00, 0xFF, 0xFF]);
+    }
+
+    #[test]
+    fn brightness_dims_the_leds() {
+        let canvas = Canvas::<32, 16>::with_background_color(Color::new(255, 100, 0));
+        let mut simulator = Simulator::<3, 32, 16>::new();
+        simulator.set_brightness(128);
+        assert_eq!(simulator.render(&canvas).led(5, 9), [128, 50, 0]);
+        simulator.set_brightness(0);
+        assert_eq!(simulator.render(&canvas).led(5, 9), [0, 0, 0]);
+    }
+
+    #[test]
+    fn blanked_panel_is_off() {
+        let mut simulator = Simulator::<3, 32, 16>::new();
+        simulator.led_matrix_mut().set_blanked(true).unwrap();
+        let frame = simulator.render(&Canvas::with_background_color(Color::WHITE));
+        assert_eq!(frame.led(31, 15), [0, 0, 0]);
+    }
+}
@@ -29,9 +29,11 @@ use cortex_m_rt::entry;
 
 use nrf52833_hal::prelude::*;
 use nrf52833_hal::Delay;
+use nrf52833_rgb_led_matrix::canvas::Canvas;
 use nrf52833_rgb_led_matrix::fonts::Font8x16;
 use nrf52833_rgb_led_matrix::init_scheduled_led_matrix_system;
 use nrf52833_rgb_led_matrix::register_panic_handler_with_logging;
+use nrf52833_rgb_led_matrix::scheduler::present;
 
 const COUNTER_DELAY_MS: u32 = 1000;
 
@@ -44,15 +46,13 @@ fn main() -> ! {
     let core_periphs = nrf52833_hal::pac::CorePeripherals::take().unwrap();
     let mut delay = Delay::new(core_periphs.SYST);
     let mut counter = 0;
+    let mut canvas = Canvas::new();
 
     loop {
-        cortex_m::interrupt::free(|cs| {
-            let mut borrowed_scheduled_led_matrix = scheduled_led_matrix.borrow(cs).borrow_mut();
-            let led_matrix = borrowed_scheduled_led_matrix.as_mut().unwrap();
-            let canvas = led_matrix.borrow_mut_canvas();
-            canvas.clear();
-            canvas.draw_number(0, 0, counter, Font8x16, Default::default());
-        });
+        canvas.clear();
+        canvas.draw_number(0, 0, counter, Font8x16, Default::default());
+        // Displayed at the end of the current frame, so a number is never half drawn
+        present(scheduled_led_matrix, &canvas).unwrap();
 
         delay.delay_ms(COUNTER_DELAY_MS);
         counter += 1;
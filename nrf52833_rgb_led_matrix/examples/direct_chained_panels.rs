@@ -0,0 +1,58 @@
+/*
+MIT License
+
+Copyright (c) 2026 Vincent Hiribarren
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+ */
+
+// Two 64x32 panels chained side by side, drawn as a single 128x32 canvas.
+// The OUT connector of the first panel is wired to the IN connector of the second one.
+
+#![no_main]
+#![no_std]
+
+use cortex_m_rt::entry;
+
+use nrf52833_rgb_led_matrix::canvas::{Canvas, Color};
+use nrf52833_rgb_led_matrix::chain::PanelChain;
+use nrf52833_rgb_led_matrix::helpers::MicrobitPinMapFor64x32;
+use nrf52833_rgb_led_matrix::ledmatrix::LedMatrix;
+use panic_halt as _;
+
+static CHAIN: PanelChain<64, 32> = PanelChain::horizontal(2);
+
+#[entry]
+fn main() -> ! {
+    let peripherals = nrf52833_hal::pac::Peripherals::take().unwrap();
+    let pins = MicrobitPinMapFor64x32::new(peripherals.P0, peripherals.P1);
+
+    let mut m = LedMatrix::<4, 128, 32>::with_pins(pins.led_matrix.into());
+    m.set_scan_mapping(&CHAIN);
+
+    let mut canvas = Canvas::<128, 32>::new();
+
+    canvas.draw_rectangle(0, 0, 43, 32, Color::BLUE);
+    canvas.draw_rectangle(43, 0, 42, 32, Color::WHITE);
+    canvas.draw_rectangle(85, 0, 43, 32, Color::RED);
+
+    loop {
+        m.draw_canvas(&canvas, Default::default()).unwrap();
+    }
+}
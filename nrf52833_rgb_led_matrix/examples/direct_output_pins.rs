@@ -0,0 +1,72 @@
+/*
+MIT License
+
+Copyright (c) 2026 Vincent Hiribarren
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+// The French flag, drawn through the embedded-hal `OutputPin` trait only, as
+// with the pins of another MCU: each color pin is set by its own call, which
+// is slower than the nRF port writes used by `LedMatrix::new`.
+
+#![no_main]
+#![no_std]
+
+use cortex_m_rt::entry;
+
+use nrf52833_hal::gpio::{Disconnected, Level, Pin};
+use nrf52833_rgb_led_matrix::canvas::{Canvas, Color};
+use nrf52833_rgb_led_matrix::helpers::MicrobitPinMapFor64x32;
+use nrf52833_rgb_led_matrix::ledmatrix::LedMatrix;
+use nrf52833_rgb_led_matrix::port::{NrfOutputPin, OutputPinPort};
+use panic_halt as _;
+
+fn output(pin: Pin<Disconnected>, level: Level) -> NrfOutputPin {
+    NrfOutputPin(pin.into_push_pull_output(level))
+}
+
+#[entry]
+fn main() -> ! {
+    let peripherals = nrf52833_hal::pac::Peripherals::take().unwrap();
+    let pins = MicrobitPinMapFor64x32::new(peripherals.P0, peripherals.P1).led_matrix;
+
+    let port = OutputPinPort {
+        colors: [pins.r1, pins.g1, pins.b1, pins.r2, pins.g2, pins.b2]
+            .map(|pin| output(pin, Level::Low)),
+        clk: output(pins.clk, Level::Low),
+    };
+    let line_ctrl = [pins.a, pins.b, pins.c, pins.d].map(|pin| output(pin, Level::Low));
+    let mut m: LedMatrix<4, 64, 32, _, _> = LedMatrix::from_parts(
+        port,
+        line_ctrl,
+        output(pins.lat, Level::Low),
+        output(pins.oe, Level::High),
+    );
+
+    let mut canvas = Canvas::with_64x32();
+
+    canvas.draw_rectangle(0, 0, 21, 32, Color::BLUE);
+    canvas.draw_rectangle(21, 0, 22, 32, Color::WHITE);
+    canvas.draw_rectangle(43, 0, 21, 32, Color::RED);
+
+    loop {
+        m.draw_canvas(&canvas, Default::default()).unwrap();
+    }
+}
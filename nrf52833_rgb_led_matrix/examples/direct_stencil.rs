@@ -75,6 +75,6 @@ fn main() -> ! {
     canvas.draw_stencil(52, 12, &Font5x7::N_3, Color::WHITE);
 
     loop {
-        m.draw_canvas(&canvas, Default::default());
+        m.draw_canvas(&canvas, Default::default()).unwrap();
     }
 }
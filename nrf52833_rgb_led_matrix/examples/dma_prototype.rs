@@ -0,0 +1,94 @@
+/*
+MIT License
+
+Copyright (c) 2026 Vincent Hiribarren
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+ */
+
+//! Scan loop using the DMA shifting prototype: each line is shifted by the PWM
+//! instances while the previous one is displayed.
+
+#![no_main]
+#![no_std]
+
+use core::ptr::addr_of_mut;
+
+use cortex_m_rt::entry;
+use nrf52833_hal::ppi;
+use nrf52833_hal::prelude::*;
+use nrf52833_hal::Delay;
+
+use nrf52833_rgb_led_matrix::bitplane::BitPlaneFrame;
+use nrf52833_rgb_led_matrix::canvas::{Canvas, Color};
+use nrf52833_rgb_led_matrix::dma::{DmaSequences, DmaShifter};
+use nrf52833_rgb_led_matrix::helpers::MicrobitPinMapFor64x32;
+use nrf52833_rgb_led_matrix::ledmatrix::{ColorBitPosition, LedMatrix};
+use panic_halt as _;
+
+const BCM_BASE_PERIOD_MICROSEC: u32 = 2;
+
+static mut SEQUENCES: DmaSequences<64> = DmaSequences::new();
+
+#[entry]
+fn main() -> ! {
+    let peripherals = nrf52833_hal::pac::Peripherals::take().unwrap();
+    let core_periphs = nrf52833_hal::pac::CorePeripherals::take().unwrap();
+    let pins = MicrobitPinMapFor64x32::new(peripherals.P0, peripherals.P1);
+    let mut delay = Delay::new(core_periphs.SYST);
+    let mut led_matrix = LedMatrix::new(pins.led_matrix);
+
+    let mut canvas = Canvas::with_64x32();
+    canvas.draw_rectangle(0, 0, 21, 32, Color::BLUE);
+    canvas.draw_rectangle(21, 0, 22, 32, Color::WHITE);
+    canvas.draw_rectangle(43, 0, 21, 32, Color::RED);
+    let frame = BitPlaneFrame::from_canvas(
+        &canvas,
+        led_matrix.color_correction(),
+        led_matrix.scan_mapping(),
+    );
+
+    let ppi = ppi::Parts::new(peripherals.PPI);
+    let mut shifter = DmaShifter::new(
+        peripherals.PWM0,
+        peripherals.PWM1,
+        peripherals.EGU0,
+        ppi.ppi0,
+        // Only borrowed here, the shifter keeps it until released
+        unsafe { &mut *addr_of_mut!(SEQUENCES) },
+        led_matrix.port(),
+    );
+
+    let scan_lines = led_matrix.scan_lines();
+    let mut displayed_bit = None;
+    loop {
+        for bit in 0..=ColorBitPosition::MSB_POSITION {
+            for line in 0..scan_lines {
+                shifter.start(frame.line_words(line, ColorBitPosition(bit)));
+                // The previous line is displayed while this one is shifted
+                if let Some(displayed_bit) = displayed_bit {
+                    delay.delay_us(BCM_BASE_PERIOD_MICROSEC << displayed_bit);
+                }
+                shifter.wait();
+                led_matrix.display_shifted_line(line).unwrap();
+                displayed_bit = Some(bit);
+            }
+        }
+    }
+}
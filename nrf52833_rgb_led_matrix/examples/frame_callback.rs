@@ -0,0 +1,66 @@
+/*
+MIT License
+
+Copyright (c) 2026 Vincent Hiribarren
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+// A bar moving by one column for each displayed frame, its position being
+// computed from the frame counter updated by the frame callback.
+
+#![no_main]
+#![no_std]
+
+use core::sync::atomic::{AtomicU32, Ordering};
+
+use cortex_m_rt::entry;
+
+use nrf52833_rgb_led_matrix::canvas::{Canvas, Color};
+use nrf52833_rgb_led_matrix::scheduler::present;
+use nrf52833_rgb_led_matrix::scheduler::with_scheduled_matrix;
+use nrf52833_rgb_led_matrix::{
+    init_scheduled_led_matrix_system, panel, register_panic_handler_with_logging,
+};
+
+static DISPLAYED_FRAMES: AtomicU32 = AtomicU32::new(0);
+
+fn on_frame_displayed(frame_count: u32) {
+    DISPLAYED_FRAMES.store(frame_count, Ordering::Relaxed);
+}
+
+#[entry]
+fn main() -> ! {
+    register_panic_handler_with_logging!();
+    let peripherals = nrf52833_hal::pac::Peripherals::take().unwrap();
+    let scheduled_led_matrix = init_scheduled_led_matrix_system!(peripherals);
+
+    with_scheduled_matrix(scheduled_led_matrix, |led_matrix| {
+        led_matrix.set_frame_callback(Some(on_frame_displayed));
+    })
+    .unwrap();
+
+    let mut canvas = Canvas::new();
+    loop {
+        let column = DISPLAYED_FRAMES.load(Ordering::Relaxed) as usize % panel::WIDTH;
+        canvas.clear();
+        canvas.draw_rectangle(column, 0, 1, panel::HEIGHT, Color::GREEN);
+        present(scheduled_led_matrix, &canvas).unwrap();
+    }
+}
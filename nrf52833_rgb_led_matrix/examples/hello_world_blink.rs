@@ -31,6 +31,7 @@ use cortex_m_rt::entry;
 use nrf52833_hal::Delay;
 use nrf52833_rgb_led_matrix::canvas::{Canvas, Color, TextOptions};
 use nrf52833_rgb_led_matrix::fonts::Font5x7;
+use nrf52833_rgb_led_matrix::scheduler::with_scheduled_matrix;
 use nrf52833_rgb_led_matrix::{
     init_scheduled_led_matrix_system, register_panic_handler_with_logging,
 };
@@ -47,11 +48,9 @@ fn main() -> ! {
 
     let mut delay = Delay::new(core_periphs.SYST);
 
-    let mut next_canvas = Canvas::with_64x32();
+    let mut next_canvas = Canvas::new();
 
-    cortex_m::interrupt::free(|cs| {
-        let mut borrowed_scheduled_led_matrix = scheduled_led_matrix.borrow(cs).borrow_mut();
-        let led_matrix = borrowed_scheduled_led_matrix.as_mut().unwrap();
+    with_scheduled_matrix(scheduled_led_matrix, |led_matrix| {
         next_canvas.draw_text(
             1,
             1,
@@ -62,7 +61,7 @@ fn main() -> ! {
                 ..Default::default()
             },
         );
-        led_matrix.swap_canvas(&mut next_canvas);
+        led_matrix.swap_canvas(&mut next_canvas).unwrap();
         next_canvas.draw_text(
             1,
             1,
@@ -73,14 +72,14 @@ fn main() -> ! {
                 ..Default::default()
             },
         );
-    });
+    })
+    .unwrap();
 
     loop {
         delay.delay_us(CANVAS_SWITCH_DELAY_MICROSEC);
-        cortex_m::interrupt::free(|cs| {
-            let mut borrowed_scheduled_led_matrix = scheduled_led_matrix.borrow(cs).borrow_mut();
-            let led_matrix = borrowed_scheduled_led_matrix.as_mut().unwrap();
-            led_matrix.swap_canvas(&mut next_canvas);
-        });
+        with_scheduled_matrix(scheduled_led_matrix, |led_matrix| {
+            led_matrix.swap_canvas(&mut next_canvas).unwrap();
+        })
+        .unwrap();
     }
 }
@@ -0,0 +1,63 @@
+/*
+MIT License
+
+Copyright (c) 2026 Vincent Hiribarren
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+// A full-screen animated gradient, drawn and converted without disabling the
+// interrupts: the display is never paused while a frame is prepared.
+
+#![no_main]
+#![no_std]
+
+use cortex_m_rt::entry;
+
+use nrf52833_rgb_led_matrix::canvas::{Canvas, Color};
+use nrf52833_rgb_led_matrix::scheduler::with_scheduled_matrix;
+use nrf52833_rgb_led_matrix::{
+    init_scheduled_led_matrix_system, panel, register_panic_handler_with_logging,
+};
+
+#[entry]
+fn main() -> ! {
+    register_panic_handler_with_logging!();
+    let peripherals = nrf52833_hal::pac::Peripherals::take().unwrap();
+    let scheduled_led_matrix = init_scheduled_led_matrix_system!(peripherals);
+
+    let mut presenter = with_scheduled_matrix(scheduled_led_matrix, |led_matrix| {
+        led_matrix.take_presenter().unwrap()
+    })
+    .unwrap();
+
+    let mut canvas = Canvas::new();
+    let mut shift: u8 = 0;
+    loop {
+        for y in 0..panel::HEIGHT {
+            for x in 0..panel::WIDTH {
+                let red = (x as u8).wrapping_add(shift).wrapping_mul(4);
+                let blue = (y as u8).wrapping_add(shift).wrapping_mul(8);
+                canvas.draw_pixel(x, y, Color::new(red, 0, blue));
+            }
+        }
+        presenter.present(&canvas);
+        shift = shift.wrapping_add(1);
+    }
+}
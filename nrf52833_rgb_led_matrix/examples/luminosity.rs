@@ -26,6 +26,7 @@ SOFTWARE.
 #![no_std]
 
 use cortex_m_rt::entry;
+use nrf52833_rgb_led_matrix::scheduler::with_scheduled_matrix;
 use nrf52833_rgb_led_matrix::{
     canvas::Color, init_scheduled_led_matrix_system, register_panic_handler_with_logging,
 };
@@ -35,9 +36,7 @@ fn main() -> ! {
     register_panic_handler_with_logging!();
     let peripherals = nrf52833_hal::pac::Peripherals::take().unwrap();
     let scheduled_led_matrix = init_scheduled_led_matrix_system!(peripherals);
-    cortex_m::interrupt::free(|cs| {
-        let mut borrowed_scheduled_led_matrix = scheduled_led_matrix.borrow(cs).borrow_mut();
-        let led_matrix = borrowed_scheduled_led_matrix.as_mut().unwrap();
+    with_scheduled_matrix(scheduled_led_matrix, |led_matrix| {
         let canvas = led_matrix.borrow_mut_canvas();
         let w = canvas.width();
         let h = canvas.height();
@@ -49,7 +48,9 @@ fn main() -> ! {
                 canvas.draw_pixel(x, y, Color::new(coeff, coeff, coeff));
             }
         }
-    });
+        led_matrix.commit_canvas().unwrap();
+    })
+    .unwrap();
     loop {
         cortex_m::asm::wfi();
     }
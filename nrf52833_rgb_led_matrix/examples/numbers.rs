@@ -26,6 +26,7 @@ SOFTWARE.
 #![no_std]
 
 use cortex_m_rt::entry;
+use nrf52833_rgb_led_matrix::scheduler::with_scheduled_matrix;
 use nrf52833_rgb_led_matrix::{
     fonts::Font5x7, init_scheduled_led_matrix_system, register_panic_handler_with_logging,
 };
@@ -36,12 +37,12 @@ fn main() -> ! {
     let peripherals = nrf52833_hal::pac::Peripherals::take().unwrap();
     let scheduled_led_matrix = init_scheduled_led_matrix_system!(peripherals);
 
-    cortex_m::interrupt::free(|cs| {
-        let mut borrowed_scheduled_led_matrix = scheduled_led_matrix.borrow(cs).borrow_mut();
-        let led_matrix = borrowed_scheduled_led_matrix.as_mut().unwrap();
+    with_scheduled_matrix(scheduled_led_matrix, |led_matrix| {
         let canvas = led_matrix.borrow_mut_canvas();
         canvas.draw_text(0, 0, "0123456789", Font5x7, Default::default());
-    });
+        led_matrix.commit_canvas().unwrap();
+    })
+    .unwrap();
 
     loop {
         cortex_m::asm::wfi();
@@ -0,0 +1,101 @@
+/*
+MIT License
+
+Copyright (c) 2026 Vincent Hiribarren
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+// The scan driven by a RTIC hardware task bound to TIMER1, the built-in
+// TIMER4 interrupt not being used. To use TIMER4 instead, build without the
+// `builtin-scheduler` feature, which declares it.
+
+#![no_main]
+#![no_std]
+
+use panic_halt as _;
+
+#[rtic::app(device = nrf52833_hal::pac)]
+mod app {
+    use nrf52833_hal::pac::TIMER1;
+    use nrf52833_rgb_led_matrix::canvas::{Canvas, Color};
+    use nrf52833_rgb_led_matrix::exchange::{FrameExchange, FramePresenter};
+    use nrf52833_rgb_led_matrix::helpers::MicrobitPinMapFor64x32;
+    use nrf52833_rgb_led_matrix::ledmatrix::LedMatrix;
+    use nrf52833_rgb_led_matrix::scheduler::ScheduledLedMatrix;
+    use nrf52833_rgb_led_matrix::timer::Timer16Mhz;
+
+    const MOVE_DELAY_CYCLES: u32 = 6_400_000;
+
+    static FRAME_EXCHANGE: FrameExchange<64, 32> = FrameExchange::new();
+
+    #[shared]
+    struct Shared {
+        led_matrix: ScheduledLedMatrix<4, 64, 32, Timer16Mhz<TIMER1>>,
+    }
+
+    #[local]
+    struct Local {
+        canvas: Canvas<64, 32>,
+        presenter: FramePresenter<64, 32>,
+    }
+
+    #[init]
+    fn init(cx: init::Context) -> (Shared, Local) {
+        let pins = MicrobitPinMapFor64x32::new(cx.device.P0, cx.device.P1);
+        let mut led_matrix = ScheduledLedMatrix::new(
+            LedMatrix::new(pins.led_matrix),
+            Timer16Mhz::new(cx.device.TIMER1),
+            &FRAME_EXCHANGE,
+        )
+        .unwrap();
+        // Canvases are then converted in idle, without locking the scheduled matrix
+        let presenter = led_matrix.take_presenter().unwrap();
+        led_matrix.start_rendering_loop().unwrap();
+        (
+            Shared { led_matrix },
+            Local {
+                canvas: Canvas::new(),
+                presenter,
+            },
+        )
+    }
+
+    #[idle(local = [canvas, presenter])]
+    fn idle(cx: idle::Context) -> ! {
+        let mut x = 0;
+        loop {
+            let canvas = &mut *cx.local.canvas;
+            canvas.clear();
+            canvas.draw_rectangle(x, 8, 16, 16, Color::BLUE);
+            cx.local.presenter.try_present(canvas);
+            x = (x + 1) % (64 - 16);
+            cortex_m::asm::delay(MOVE_DELAY_CYCLES);
+        }
+    }
+
+    #[task(binds = TIMER1, shared = [led_matrix], priority = 2)]
+    fn refresh(mut cx: refresh::Context) {
+        // On error, the rendering loop is stopped and the LEDs stay off
+        let _ = cx
+            .shared
+            .led_matrix
+            .lock(|led_matrix| led_matrix.on_timer_interrupt());
+    }
+}
@@ -0,0 +1,176 @@
+/*
+MIT License
+
+Copyright (c) 2026 Vincent Hiribarren
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! A canvas converted in advance into what is shifted out to the panel.
+//!
+//! For each line and each bit position, the frame stores one color word per
+//! shifted pixel: the levels of the R1, G1, B1, R2, G2, B2 pins packed in 6 bits,
+//! in the order given by the [`ScanMapping`] of the panel.
+//! [`LedMatrix`](crate::ledmatrix::LedMatrix) maps each color word to its
+//! precomputed port masks, so drawing a line of a frame does not need to read
+//! and mask the canvas colors anymore.
+//!
+//! Storing the port masks themselves would save this lookup, but a mask holds a
+//! 32-bit word for each GPIO port, 8 bytes instead of 1: a 64x32 frame would
+//! take 64 KB instead of 8 KB, half of the RAM, while two or three frames are
+//! needed. The lookup is a single indexed load from a table of 64 masks, and
+//! keeps the frames usable with any [`PortWrite`](crate::port::PortWrite).
+
+use crate::canvas::Canvas;
+use crate::gamma::ColorCorrection;
+use crate::ledmatrix::{color_word, ColorBitPosition};
+use crate::scan::{PanelHalf, ScanMapping};
+
+/// One plane for each bit of a color channel
+pub const BIT_PLANES_NB: usize = 8;
+
+pub struct BitPlaneFrame<const WIDTH: usize, const HEIGHT: usize> {
+    // BIT_PLANES_NB * scan lines * chain length color words, which is always
+    // BIT_PLANES_NB * HEIGHT/2 * WIDTH. `HEIGHT / 2` cannot be used in an array
+    // length with const generics, hence this flattened shape.
+    words: [[[u8; WIDTH]; HEIGHT]; BIT_PLANES_NB / 2],
+    // Set by the mapping of the last conversion
+    scan_lines: usize,
+    chain_length: usize,
+}
+
+impl<const WIDTH: usize, const HEIGHT: usize> Default for BitPlaneFrame<WIDTH, HEIGHT> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const WIDTH: usize, const HEIGHT: usize> BitPlaneFrame<WIDTH, HEIGHT> {
+    /// Frame with all the LEDs off
+    pub const fn new() -> Self {
+        BitPlaneFrame {
+            words: [[[0; WIDTH]; HEIGHT]; BIT_PLANES_NB / 2],
+            scan_lines: HEIGHT / 2,
+            chain_length: WIDTH,
+        }
+    }
+
+    pub fn from_canvas(
+        canvas: &Canvas<WIDTH, HEIGHT>,
+        color_correction: &ColorCorrection,
+        scan_mapping: &dyn ScanMapping<WIDTH, HEIGHT>,
+    ) -> Self {
+        let mut frame = Self::new();
+        frame.update_from_canvas(canvas, color_correction, scan_mapping);
+        frame
+    }
+
+    /// Convert the whole canvas; this is done in the caller context, not while displaying.
+    pub fn update_from_canvas(
+        &mut self,
+        canvas: &Canvas<WIDTH, HEIGHT>,
+        color_correction: &ColorCorrection,
+        scan_mapping: &dyn ScanMapping<WIDTH, HEIGHT>,
+    ) {
+        self.scan_lines = scan_mapping.scan_lines();
+        self.chain_length = scan_mapping.chain_length();
+        let raw_canvas = canvas.as_ref();
+        for line in 0..self.scan_lines {
+            for index in 0..self.chain_length {
+                let (top_x, top_y) = scan_mapping.pixel_position(line, index, PanelHalf::Top);
+                let (bottom_x, bottom_y) =
+                    scan_mapping.pixel_position(line, index, PanelHalf::Bottom);
+                let color_top = color_correction.apply(&raw_canvas[top_y][top_x]);
+                let color_bottom = color_correction.apply(&raw_canvas[bottom_y][bottom_x]);
+                for plane in 0..BIT_PLANES_NB {
+                    let bit_position = ColorBitPosition(plane as u8);
+                    let offset = self.line_offset(line, bit_position);
+                    self.words.as_flattened_mut().as_flattened_mut()[offset + index] =
+                        color_word(&color_top, &color_bottom, bit_position) as u8;
+                }
+            }
+        }
+    }
+
+    /// Color words to shift out for `line`, between `0` and the number of scan lines.
+    #[inline(always)]
+    pub fn line_words(&self, line: usize, bit_position: ColorBitPosition) -> &[u8] {
+        let offset = self.line_offset(line, bit_position);
+        &self.words.as_flattened().as_flattened()[offset..offset + self.chain_length]
+    }
+
+    #[inline(always)]
+    fn line_offset(&self, line: usize, bit_position: ColorBitPosition) -> usize {
+        (bit_position.0 as usize * self.scan_lines + line) * self.chain_length
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scan::{HalfScan, StripedScan};
+    use crate::test_util::gradient_canvas;
+
+    fn assert_words_match(mapping: &dyn ScanMapping<16, 8>, color_correction: &ColorCorrection) {
+        let canvas = gradient_canvas::<16, 8>();
+        let frame = BitPlaneFrame::from_canvas(&canvas, color_correction, mapping);
+        for line in 0..mapping.scan_lines() {
+            for plane in 0..BIT_PLANES_NB {
+                let bit_position = ColorBitPosition(plane as u8);
+                let words = frame.line_words(line, bit_position);
+                assert_eq!(words.len(), mapping.chain_length());
+                for (index, &word) in words.iter().enumerate() {
+                    let (top_x, top_y) = mapping.pixel_position(line, index, PanelHalf::Top);
+                    let (bottom_x, bottom_y) =
+                        mapping.pixel_position(line, index, PanelHalf::Bottom);
+                    let expected = color_word(
+                        &color_correction.apply(&canvas.0[top_y][top_x]),
+                        &color_correction.apply(&canvas.0[bottom_y][bottom_x]),
+                        bit_position,
+                    );
+                    assert_eq!(word as usize, expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn new_frame_is_off() {
+        let frame = BitPlaneFrame::<16, 8>::new();
+        for line in 0..4 {
+            assert!(frame
+                .line_words(line, ColorBitPosition(7))
+                .iter()
+                .all(|&w| w == 0));
+        }
+    }
+
+    #[test]
+    fn words_match_the_canvas() {
+        assert_words_match(&HalfScan, &ColorCorrection::IDENTITY);
+        assert_words_match(&HalfScan, &ColorCorrection::from_gamma(2.2));
+        let striped = StripedScan {
+            scan_lines: 2,
+            stripe_width: 4,
+            bottom_row_first: false,
+            zigzag: true,
+        };
+        assert_words_match(&striped, &ColorCorrection::IDENTITY);
+    }
+}
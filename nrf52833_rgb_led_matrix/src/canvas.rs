@@ -33,7 +33,7 @@ pub type Stencil14x32 = Stencil<14, 32>;
 pub struct Stencil<const W: usize, const H: usize>(pub [[u8; W]; H]);
 
 #[derive(Clone, Copy, PartialEq)]
-#[cfg_attr(feature = "logging", derive(Debug))]
+#[cfg_attr(any(test, feature = "logging"), derive(Debug))]
 pub struct Color([u8; 3]);
 
 impl Color {
@@ -127,6 +127,12 @@ impl Canvas<64, 32> {
     }
 }
 
+impl Canvas<64, 64> {
+    pub const fn with_64x64() -> Self {
+        Canvas::<64, 64>::new()
+    }
+}
+
 impl<const WIDTH: usize, const HEIGHT: usize> Default for Canvas<WIDTH, HEIGHT> {
     fn default() -> Self {
         Self::new()
@@ -314,3 +320,86 @@ impl<const WIDTH: usize, const HEIGHT: usize> AsMut<[[Color; WIDTH]; HEIGHT]>
         &mut self.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fonts::Font5x7;
+
+    const GREY: Color = Color::new(100, 100, 100);
+
+    #[test]
+    fn draw_pixel_out_of_bounds_is_ignored() {
+        let mut canvas = Canvas::<4, 3>::new();
+        canvas
+            .draw_pixel(4, 0, Color::RED)
+            .draw_pixel(0, 3, Color::RED);
+        assert!(canvas.0.iter().flatten().all(|&c| c == Color::BLACK));
+        canvas.draw_pixel(3, 2, Color::RED);
+        assert_eq!(canvas.0[2][3], Color::RED);
+    }
+
+    #[test]
+    fn draw_rectangle_is_clipped() {
+        let mut canvas = Canvas::<4, 3>::new();
+        canvas.draw_rectangle(2, 1, 10, 10, Color::GREEN);
+        for (y, row) in canvas.0.iter().enumerate() {
+            for (x, &color) in row.iter().enumerate() {
+                let inside = x >= 2 && y >= 1;
+                assert_eq!(color == Color::GREEN, inside, "pixel ({x}, {y})");
+            }
+        }
+    }
+
+    #[test]
+    fn draw_canvas_blend_modes() {
+        let mut sprite = Canvas::<2, 1>::new();
+        sprite.draw_pixel(1, 0, Color::BLUE);
+
+        let mut canvas = Canvas::<3, 1>::with_background_color(GREY);
+        canvas.draw_canvas(1, 0, &sprite, BlendMode::TransparentBlack);
+        assert_eq!(canvas.0[0], [GREY, GREY, Color::BLUE]);
+
+        let mut canvas = Canvas::<3, 1>::with_background_color(GREY);
+        canvas.draw_canvas(1, 0, &sprite, BlendMode::Replace);
+        assert_eq!(canvas.0[0], [GREY, Color::BLACK, Color::BLUE]);
+    }
+
+    #[test]
+    fn draw_text_places_glyphs_with_interspace() {
+        let mut canvas = Canvas::<12, 7>::new();
+        canvas.draw_text(0, 0, "11", Font5x7, TextOptions::default());
+        for (y, row) in Font5x7::N_1.0.iter().enumerate() {
+            for (x, &bit) in row.iter().enumerate() {
+                let expected = if bit == 0 { Color::BLACK } else { Color::WHITE };
+                assert_eq!(canvas.0[y][x], expected);
+                assert_eq!(canvas.0[y][x + 6], expected);
+            }
+            assert_eq!(canvas.0[y][5], Color::BLACK);
+        }
+    }
+
+    #[test]
+    fn draw_number_matches_draw_text() {
+        let mut from_number = Canvas::<32, 7>::new();
+        from_number.draw_number(1, 0, 10_207, Font5x7, TextOptions::default());
+        let mut from_text = Canvas::<32, 7>::new();
+        from_text.draw_text(1, 0, "10207", Font5x7, TextOptions::default());
+        assert_eq!(from_number.0, from_text.0);
+
+        let mut zero = Canvas::<5, 7>::new();
+        zero.draw_number(0, 0, 0, Font5x7, TextOptions::default());
+        let mut from_char = Canvas::<5, 7>::new();
+        from_char.draw_char(0, 0, '0', Color::WHITE, Font5x7);
+        assert_eq!(zero.0, from_char.0);
+    }
+
+    #[test]
+    fn apply_luminosity_scales_channels() {
+        let mut canvas = Canvas::<1, 1>::with_background_color(Color::new(255, 100, 0));
+        canvas.apply_luminosity(255);
+        assert_eq!(canvas.0[0][0], Color::new(255, 100, 0));
+        canvas.apply_luminosity(0);
+        assert_eq!(canvas.0[0][0], Color::BLACK);
+    }
+}
@@ -0,0 +1,306 @@
+/*
+MIT License
+
+Copyright (c) 2026 Vincent Hiribarren
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Several panels daisy-chained on the same connector, seen as a single canvas.
+//!
+//! The panels share the address lines, the shift registers of their halves
+//! being chained: the first pixels shifted out end up in the last panel of the
+//! chain. A [`PanelChain`] describes where each panel is in the virtual canvas,
+//! and is used as the scan mapping of the [`LedMatrix`](crate::ledmatrix::LedMatrix)
+//! of the whole canvas:
+//!
+//! ```ignore
+//! // Two 64x32 panels, the second one on the right of the first one
+//! static CHAIN: PanelChain<64, 32> = PanelChain::horizontal(2);
+//! let mut led_matrix = LedMatrix::<4, 128, 32>::with_pins(pins.into());
+//! led_matrix.set_scan_mapping(&CHAIN);
+//! ```
+
+use crate::scan::{HalfScan, PanelHalf, ScanMapping};
+
+/// How the panels follow each other along the chain
+#[derive(Copy, Clone, PartialEq)]
+pub enum ChainLayout {
+    /// From left to right, then on the next row of panels
+    Horizontal,
+    /// From top to bottom, then on the next column of panels
+    Vertical,
+}
+
+/// Clockwise rotation of a panel in the virtual canvas
+#[derive(Copy, Clone, Default, PartialEq)]
+pub enum Rotation {
+    #[default]
+    None,
+    Quarter,
+    Half,
+    ThreeQuarters,
+}
+
+impl Rotation {
+    /// Position in the rotated panel of the pixel `(x, y)` of the panel
+    fn apply(self, x: usize, y: usize, width: usize, height: usize) -> (usize, usize) {
+        match self {
+            Rotation::None => (x, y),
+            Rotation::Quarter => (height - 1 - y, x),
+            Rotation::Half => (width - 1 - x, height - 1 - y),
+            Rotation::ThreeQuarters => (y, width - 1 - x),
+        }
+    }
+
+    fn is_quarter_turn(self) -> bool {
+        matches!(self, Rotation::Quarter | Rotation::ThreeQuarters)
+    }
+}
+
+/// Chain of panels of `PANEL_WIDTH` x `PANEL_HEIGHT` pixels.
+///
+/// All the panels must have the same size once rotated, so quarter turns must be
+/// applied to all the panels, unless they are square: a chain mixing them is
+/// rejected by [`LedMatrix::set_scan_mapping`](crate::ledmatrix::LedMatrix::set_scan_mapping).
+pub struct PanelChain<const PANEL_WIDTH: usize, const PANEL_HEIGHT: usize> {
+    /// Scan mapping of a single panel
+    pub panel_mapping: &'static dyn ScanMapping<PANEL_WIDTH, PANEL_HEIGHT>,
+    pub panel_count: usize,
+    pub layout: ChainLayout,
+    /// Number of panels in a row of panels for [`ChainLayout::Horizontal`],
+    /// in a column for [`ChainLayout::Vertical`], not 0
+    pub panels_per_row: usize,
+    /// Every other row (or column) of panels goes backwards, which shortens the cables
+    pub serpentine: bool,
+    /// Rotation of each panel, from the first one of the chain. Missing ones are not rotated.
+    pub rotations: &'static [Rotation],
+}
+
+impl<const PANEL_WIDTH: usize, const PANEL_HEIGHT: usize> PanelChain<PANEL_WIDTH, PANEL_HEIGHT> {
+    /// Panels of the default mapping on a single row, the first one on the left
+    pub const fn horizontal(panel_count: usize) -> Self {
+        PanelChain {
+            panel_mapping: &HalfScan,
+            panel_count,
+            layout: ChainLayout::Horizontal,
+            panels_per_row: panel_count,
+            serpentine: false,
+            rotations: &[],
+        }
+    }
+
+    /// Panels of the default mapping on a single column, the first one at the top
+    pub const fn vertical(panel_count: usize) -> Self {
+        PanelChain {
+            layout: ChainLayout::Vertical,
+            ..Self::horizontal(panel_count)
+        }
+    }
+
+    fn rotation(&self, panel: usize) -> Rotation {
+        self.rotations.get(panel).copied().unwrap_or_default()
+    }
+
+    /// Same size once rotated for all the panels
+    fn has_uniform_cells(&self) -> bool {
+        PANEL_WIDTH == PANEL_HEIGHT
+            || (0..self.panel_count).all(|panel| {
+                self.rotation(panel).is_quarter_turn() == self.rotation(0).is_quarter_turn()
+            })
+    }
+
+    /// Size of a panel in the virtual canvas, the same for all of them
+    fn cell_size(&self) -> (usize, usize) {
+        if self.rotation(0).is_quarter_turn() {
+            (PANEL_HEIGHT, PANEL_WIDTH)
+        } else {
+            (PANEL_WIDTH, PANEL_HEIGHT)
+        }
+    }
+
+    /// Panels arranged in a `width` x `height` canvas, as many rows (or columns)
+    /// of panels as needed for all of them
+    fn fits(&self, width: usize, height: usize) -> bool {
+        if self.panels_per_row == 0 {
+            return false;
+        }
+        let rows = self.panel_count.div_ceil(self.panels_per_row);
+        let (cell_width, cell_height) = self.cell_size();
+        match self.layout {
+            ChainLayout::Horizontal => {
+                self.panels_per_row * cell_width == width && rows * cell_height == height
+            }
+            ChainLayout::Vertical => {
+                self.panels_per_row * cell_height == height && rows * cell_width == width
+            }
+        }
+    }
+
+    /// Position in the virtual canvas of the top left corner of the `panel`th panel of the chain
+    fn panel_origin(&self, panel: usize) -> (usize, usize) {
+        let mut row = panel / self.panels_per_row;
+        let mut col = panel % self.panels_per_row;
+        if self.serpentine && row % 2 == 1 {
+            col = self.panels_per_row - 1 - col;
+        }
+        if self.layout == ChainLayout::Vertical {
+            core::mem::swap(&mut row, &mut col);
+        }
+        let (cell_width, cell_height) = self.cell_size();
+        (col * cell_width, row * cell_height)
+    }
+}
+
+impl<
+        const PANEL_WIDTH: usize,
+        const PANEL_HEIGHT: usize,
+        const WIDTH: usize,
+        const HEIGHT: usize,
+    > ScanMapping<WIDTH, HEIGHT> for PanelChain<PANEL_WIDTH, PANEL_HEIGHT>
+{
+    fn scan_lines(&self) -> usize {
+        self.panel_mapping.scan_lines()
+    }
+
+    fn chain_length(&self) -> usize {
+        self.panel_mapping.chain_length() * self.panel_count
+    }
+
+    fn pixel_position(&self, line: usize, index: usize, half: PanelHalf) -> (usize, usize) {
+        let panel_chain_length = self.panel_mapping.chain_length();
+        // The first shifted pixels are pushed up to the last panel
+        let panel = self.panel_count - 1 - index / panel_chain_length;
+        let (x, y) = self
+            .panel_mapping
+            .pixel_position(line, index % panel_chain_length, half);
+        let (x, y) = self.rotation(panel).apply(x, y, PANEL_WIDTH, PANEL_HEIGHT);
+        let (origin_x, origin_y) = self.panel_origin(panel);
+        (origin_x + x, origin_y + y)
+    }
+
+    fn is_valid(&self) -> bool {
+        self.panel_mapping.is_valid() && self.has_uniform_cells() && self.fits(WIDTH, HEIGHT)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scan::tests::assert_covers_canvas;
+
+    #[test]
+    fn first_pixels_go_to_the_last_panel() {
+        let chain = PanelChain::<64, 32>::horizontal(2);
+        let position =
+            |index| ScanMapping::<128, 32>::pixel_position(&chain, 3, index, PanelHalf::Top);
+        assert_eq!(position(0), (64, 3));
+        assert_eq!(position(63), (127, 3));
+        assert_eq!(position(64), (0, 3));
+        assert_covers_canvas::<128, 32>(&chain);
+    }
+
+    #[test]
+    fn vertical_chain() {
+        let chain = PanelChain::<64, 32>::vertical(2);
+        assert_eq!(
+            ScanMapping::<64, 64>::pixel_position(&chain, 0, 0, PanelHalf::Top),
+            (0, 32)
+        );
+        assert_covers_canvas::<64, 64>(&chain);
+    }
+
+    #[test]
+    fn serpentine_chain_with_rotations() {
+        static ROTATIONS: [Rotation; 4] = [
+            Rotation::None,
+            Rotation::None,
+            Rotation::Half,
+            Rotation::Half,
+        ];
+        let chain = PanelChain::<32, 16> {
+            panels_per_row: 2,
+            serpentine: true,
+            rotations: &ROTATIONS,
+            ..PanelChain::horizontal(4)
+        };
+        // The last panel is the bottom left one, upside down
+        assert_eq!(
+            ScanMapping::<64, 32>::pixel_position(&chain, 0, 0, PanelHalf::Top),
+            (31, 31)
+        );
+        assert_covers_canvas::<64, 32>(&chain);
+    }
+
+    #[test]
+    fn quarter_turns() {
+        static ROTATIONS: [Rotation; 2] = [Rotation::Quarter, Rotation::ThreeQuarters];
+        let chain = PanelChain::<32, 16> {
+            rotations: &ROTATIONS,
+            ..PanelChain::horizontal(2)
+        };
+        assert_covers_canvas::<32, 32>(&chain);
+        assert!(ScanMapping::<32, 32>::is_valid(&chain));
+    }
+
+    #[test]
+    fn chain_must_fit_the_canvas() {
+        let chain = PanelChain::<64, 32>::horizontal(2);
+        assert!(ScanMapping::<128, 32>::is_valid(&chain));
+        // Same area, other shape
+        assert!(!ScanMapping::<64, 64>::is_valid(&chain));
+        assert!(ScanMapping::<64, 64>::is_valid(
+            &PanelChain::<64, 32>::vertical(2)
+        ));
+        let chain = PanelChain::<64, 32> {
+            panels_per_row: 1,
+            ..PanelChain::horizontal(2)
+        };
+        assert!(ScanMapping::<64, 64>::is_valid(&chain));
+        assert!(!ScanMapping::<128, 32>::is_valid(&chain));
+        let chain = PanelChain::<64, 32> {
+            panels_per_row: 0,
+            ..PanelChain::horizontal(2)
+        };
+        assert!(!ScanMapping::<128, 32>::is_valid(&chain));
+    }
+
+    #[test]
+    fn mixed_quarter_turns_are_rejected() {
+        static ROTATIONS: [Rotation; 2] = [Rotation::Half, Rotation::Quarter];
+        let chain = PanelChain::<32, 16> {
+            rotations: &ROTATIONS,
+            ..PanelChain::horizontal(2)
+        };
+        assert!(!ScanMapping::<48, 32>::is_valid(&chain));
+        // A missing rotation is no rotation
+        let chain = PanelChain::<32, 16> {
+            rotations: &ROTATIONS[1..],
+            ..PanelChain::horizontal(2)
+        };
+        assert!(!ScanMapping::<48, 32>::is_valid(&chain));
+        // Square panels keep their size
+        let chain = PanelChain::<32, 32> {
+            rotations: &ROTATIONS,
+            ..PanelChain::horizontal(2)
+        };
+        assert!(ScanMapping::<64, 32>::is_valid(&chain));
+        assert_covers_canvas::<64, 32>(&chain);
+    }
+}
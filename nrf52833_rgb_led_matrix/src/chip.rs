@@ -0,0 +1,73 @@
+/*
+MIT License
+
+Copyright (c) 2026 Vincent Hiribarren
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Driver chips of the panel shift registers.
+//!
+//! Most panels use plain shift registers, displaying what is latched right away.
+//! Others, with FM6126A or ICN2038S chips, stay dark until some of their
+//! configuration registers are written. A register is written by shifting its
+//! value into every chip, LAT being held high during the last clocks: the
+//! number of clocks with LAT high selects the register.
+//!
+//! Sequence and values from https://github.com/hzeller/rpi-rgb-led-matrix
+
+/// Driver chip of the panel, which may need an initialization before displaying anything
+#[derive(Copy, Clone, Default, PartialEq)]
+pub enum PanelChip {
+    /// Plain shift registers, nothing to initialize
+    #[default]
+    Generic,
+    Fm6126a,
+    /// Same initialization as the FM6126A
+    Icn2038s,
+}
+
+/// Value and register number of a configuration register write
+pub(crate) struct ChipRegisterWrite {
+    /// 16 bits, shifted out MSB first, repeated for each chip of the chain
+    pub value: u16,
+    pub register: usize,
+}
+
+/// Full brightness
+const FM6126A_REGISTER_12: ChipRegisterWrite = ChipRegisterWrite {
+    value: 0b0111_1111_1111_1111,
+    register: 12,
+};
+
+/// Panel on
+const FM6126A_REGISTER_13: ChipRegisterWrite = ChipRegisterWrite {
+    value: 0b0000_0000_0100_0000,
+    register: 13,
+};
+
+impl PanelChip {
+    /// Register writes to do once, before displaying the first line
+    pub(crate) fn init_sequence(self) -> &'static [ChipRegisterWrite] {
+        match self {
+            PanelChip::Generic => &[],
+            PanelChip::Fm6126a | PanelChip::Icn2038s => &[FM6126A_REGISTER_12, FM6126A_REGISTER_13],
+        }
+    }
+}
@@ -0,0 +1,250 @@
+/*
+MIT License
+
+Copyright (c) 2026 Vincent Hiribarren
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Prototype of a backend shifting the lines with EasyDMA instead of the CPU,
+//! behind the `dma-prototype` feature. It has not been validated on a panel yet.
+//!
+//! SPIM and I2S only have a single data output, while the six color lines of the
+//! panel are sampled on the same CLK edge. The PWM peripheral has four outputs,
+//! whose compare values are read from RAM by EasyDMA, one per output and per PWM
+//! period. With a period of a few 16 MHz ticks, each period shifts one pixel:
+//! the top PWM outputs R1, G1, B1 and CLK, the bottom one R2, G2, B2. A PPI
+//! channel starts both sequences from the same EGU event, so they stay in step.
+//!
+//! The next line is shifted while the current one is displayed, the CPU only
+//! fills the sequences, and latches the lines with
+//! [`LedMatrix::display_shifted_line`](crate::ledmatrix::LedMatrix::display_shifted_line):
+//!
+//! ```ignore
+//! static mut SEQUENCES: DmaSequences<64> = DmaSequences::new();
+//! let ppi = ppi::Parts::new(p.PPI);
+//! let mut shifter = DmaShifter::new(
+//!     p.PWM0,
+//!     p.PWM1,
+//!     p.EGU0,
+//!     ppi.ppi0,
+//!     unsafe { &mut *core::ptr::addr_of_mut!(SEQUENCES) },
+//!     led_matrix.port(),
+//! );
+//! shifter.start(frame.line_words(line, bit_position));
+//! // ... display the previous line ...
+//! shifter.wait();
+//! led_matrix.display_shifted_line(line)?;
+//! ```
+//!
+//! The color words still have to be expanded into 16-bit compare values, two
+//! sequences of 8 bytes per pixel, which is why the whole frame is not converted
+//! in advance, and why the scheduler does not use this backend yet.
+
+use core::ops::Deref;
+use core::sync::atomic::{compiler_fence, Ordering};
+
+use nrf52833_hal::pac::{egu0, pwm0};
+use nrf52833_hal::ppi::ConfigurablePpi;
+use nrf52833_hal::pwm;
+
+use crate::ledmatrix::COLOR_PIN_COUNT;
+use crate::port::NrfColorPort;
+
+/// Outputs of a PWM instance, each one with its own compare value
+const PWM_CHANNEL_COUNT: usize = 4;
+
+/// Color pins driven by each PWM instance
+const HALF_COLOR_PIN_COUNT: usize = COLOR_PIN_COUNT / 2;
+
+/// PWM period of a shifted pixel, in 16 MHz ticks: a 4 MHz clock
+const PIXEL_PERIOD_TICKS: u16 = 4;
+
+/// Bit 15 of a compare value: the output starts high and falls at the compare
+/// value, instead of starting low and rising.
+const FALLING_EDGE: u16 = 0x8000;
+
+/// The compare value is never reached, the output keeps its initial level
+const LEVEL_HIGH: u16 = FALLING_EDGE | PIXEL_PERIOD_TICKS;
+const LEVEL_LOW: u16 = PIXEL_PERIOD_TICKS;
+
+/// Rising edge in the middle of the period, the colors being stable since its start
+const CLOCK_PULSE: u16 = PIXEL_PERIOD_TICKS / 2;
+
+/// PWM compare values of one shifted pixel, for each output
+type PixelValues = [u16; PWM_CHANNEL_COUNT];
+
+/// Sequences read by EasyDMA, for lines of up to `CHAIN_LENGTH` pixels. They
+/// must be in RAM, hence in a static.
+pub struct DmaSequences<const CHAIN_LENGTH: usize> {
+    top: [PixelValues; CHAIN_LENGTH],
+    bottom: [PixelValues; CHAIN_LENGTH],
+}
+
+impl<const CHAIN_LENGTH: usize> Default for DmaSequences<CHAIN_LENGTH> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const CHAIN_LENGTH: usize> DmaSequences<CHAIN_LENGTH> {
+    pub const fn new() -> Self {
+        DmaSequences {
+            top: [[LEVEL_LOW; PWM_CHANNEL_COUNT]; CHAIN_LENGTH],
+            bottom: [[LEVEL_LOW; PWM_CHANNEL_COUNT]; CHAIN_LENGTH],
+        }
+    }
+}
+
+/// Compare values of the top and bottom PWM for `color_word`
+fn pixel_values(color_word: u8) -> (PixelValues, PixelValues) {
+    let level = |pin_idx: usize| {
+        if color_word & (1 << pin_idx) != 0 {
+            LEVEL_HIGH
+        } else {
+            LEVEL_LOW
+        }
+    };
+    let top = [level(0), level(1), level(2), CLOCK_PULSE];
+    let bottom = [level(3), level(4), level(5), LEVEL_LOW];
+    (top, bottom)
+}
+
+/// Shift the color words of a line with two PWM instances, without the CPU.
+pub struct DmaShifter<const CHAIN_LENGTH: usize, TOP, BOTTOM, EGU, PPI> {
+    pwm_top: TOP,
+    pwm_bottom: BOTTOM,
+    egu: EGU,
+    ppi: PPI,
+    sequences: &'static mut DmaSequences<CHAIN_LENGTH>,
+    shifting: bool,
+}
+
+impl<const CHAIN_LENGTH: usize, TOP, BOTTOM, EGU, PPI>
+    DmaShifter<CHAIN_LENGTH, TOP, BOTTOM, EGU, PPI>
+where
+    TOP: pwm::Instance,
+    BOTTOM: pwm::Instance,
+    EGU: Deref<Target = egu0::RegisterBlock>,
+    PPI: ConfigurablePpi,
+{
+    /// The PWM instances take over the color and clock pins of `color_port`,
+    /// which must not be written anymore, the panel chip being initialized before.
+    pub fn new(
+        pwm_top: TOP,
+        pwm_bottom: BOTTOM,
+        egu: EGU,
+        mut ppi: PPI,
+        sequences: &'static mut DmaSequences<CHAIN_LENGTH>,
+        color_port: &NrfColorPort,
+    ) -> Self {
+        let (colors, clk) = color_port.pins();
+        let (top_colors, bottom_colors) = colors.split_at(HALF_COLOR_PIN_COUNT);
+        for (pwm, pins) in [
+            (&*pwm_top as &pwm0::RegisterBlock, top_colors),
+            (&*pwm_bottom, bottom_colors),
+        ] {
+            for (channel, pin) in pins.iter().enumerate() {
+                pwm.psel.out[channel].write(|w| unsafe { w.bits(pin.psel_bits()) });
+            }
+            pwm.mode.write(|w| w.updown().up());
+            pwm.prescaler.write(|w| w.prescaler().div_1());
+            pwm.countertop
+                .write(|w| unsafe { w.countertop().bits(PIXEL_PERIOD_TICKS) });
+            pwm.loop_.write(|w| w.cnt().disabled());
+            pwm.decoder
+                .write(|w| w.load().individual().mode().refresh_count());
+            pwm.seq0.refresh.write(|w| unsafe { w.bits(0) });
+            pwm.seq0.enddelay.write(|w| unsafe { w.bits(0) });
+            // The last pixel is fully shifted before the outputs stop
+            pwm.shorts.write(|w| w.seqend0_stop().enabled());
+            pwm.enable.write(|w| w.enable().enabled());
+        }
+        pwm_top.psel.out[HALF_COLOR_PIN_COUNT].write(|w| unsafe { w.bits(clk.psel_bits()) });
+
+        ppi.set_event_endpoint(&egu.events_triggered[0]);
+        ppi.set_task_endpoint(&pwm_top.tasks_seqstart[0]);
+        ppi.set_fork_task_endpoint(&pwm_bottom.tasks_seqstart[0]);
+        ppi.enable();
+
+        DmaShifter {
+            pwm_top,
+            pwm_bottom,
+            egu,
+            ppi,
+            sequences,
+            shifting: false,
+        }
+    }
+
+    /// Start shifting `words`, once the previous line is shifted. The words
+    /// beyond `CHAIN_LENGTH` are ignored.
+    pub fn start(&mut self, words: &[u8]) {
+        self.wait();
+        let words = &words[..words.len().min(CHAIN_LENGTH)];
+        if words.is_empty() {
+            return;
+        }
+        for (index, &word) in words.iter().enumerate() {
+            (self.sequences.top[index], self.sequences.bottom[index]) = pixel_values(word);
+        }
+        let value_count = (words.len() * PWM_CHANNEL_COUNT) as u32;
+        for (pwm, sequence) in [
+            (&*self.pwm_top as &pwm0::RegisterBlock, &self.sequences.top),
+            (&*self.pwm_bottom, &self.sequences.bottom),
+        ] {
+            pwm.seq0
+                .ptr
+                .write(|w| unsafe { w.bits(sequence.as_ptr() as u32) });
+            pwm.seq0.cnt.write(|w| unsafe { w.bits(value_count) });
+            pwm.events_stopped.reset();
+        }
+        // The sequences must be in RAM before EasyDMA reads them
+        compiler_fence(Ordering::SeqCst);
+        self.shifting = true;
+        self.egu.tasks_trigger[0].write(|w| unsafe { w.bits(1) });
+    }
+
+    /// True until both PWM instances have stopped at the end of the line
+    pub fn is_busy(&self) -> bool {
+        self.shifting
+            && (self.pwm_top.events_stopped.read().bits() == 0
+                || self.pwm_bottom.events_stopped.read().bits() == 0)
+    }
+
+    /// Wait for the end of the started line, before latching it.
+    pub fn wait(&mut self) {
+        while self.is_busy() {}
+        compiler_fence(Ordering::SeqCst);
+        self.shifting = false;
+    }
+
+    /// Disable the PWM instances, which give the pins back to the GPIO.
+    pub fn release(mut self) -> (TOP, BOTTOM, EGU, PPI) {
+        self.wait();
+        self.ppi.disable();
+        for pwm in [&*self.pwm_top as &pwm0::RegisterBlock, &*self.pwm_bottom] {
+            pwm.enable.write(|w| w.enable().disabled());
+            for psel in &pwm.psel.out {
+                psel.reset();
+            }
+        }
+        (self.pwm_top, self.pwm_bottom, self.egu, self.ppi)
+    }
+}
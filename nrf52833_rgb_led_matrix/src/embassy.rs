@@ -0,0 +1,264 @@
+/*
+MIT License
+
+Copyright (c) 2026 Vincent Hiribarren
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Async alternative to the [`scheduler`](crate::scheduler), for embassy applications.
+//!
+//! A [`MatrixRunner`] scans the panel from an async task, waiting for each BCM
+//! slot with an embassy timer, and displays the frames of a [`FrameExchange`].
+//! The application presents its canvases with an [`AsyncFramePresenter`],
+//! nothing being shared through a `cortex_m::interrupt::free` critical section.
+//!
+//!     static EXCHANGE: FrameExchange<64, 32> = FrameExchange::new();
+//!     static FRAME_SIGNAL: FrameSignal = FrameSignal::new();
+//!
+//!     #[embassy_executor::task]
+//!     async fn matrix_task(runner: MatrixRunner<4, 64, 32>) {
+//!         let error = runner.run().await;
+//!         log!("Matrix runner stopped: {:?}", error);
+//!     }
+//!
+//!     let (runner, mut presenter) = split(led_matrix, &EXCHANGE, &FRAME_SIGNAL).unwrap();
+//!     spawner.spawn(matrix_task(runner)).unwrap();
+//!     loop {
+//!         canvas.draw_text(...);
+//!         presenter.present(&canvas).await;
+//!     }
+//!
+//! The time driver comes from the application, for instance `embassy-nrf`. The
+//! BCM slots last whole ticks of it, so they keep their weights: its rate must be
+//! high for a short base period. As the task competes with the others, running it
+//! on a high priority `InterruptExecutor` avoids a flickering display.
+
+use embassy_sync::blocking_mutex::raw::CriticalSectionRawMutex;
+use embassy_sync::signal::Signal;
+use embassy_time::{Duration, Timer};
+use embedded_hal::digital::OutputPin;
+
+use crate::canvas::Canvas;
+use crate::error::{Error, Result};
+use crate::exchange::{FrameExchange, FramePresenter};
+use crate::ledmatrix::{ColorBitPosition, LedMatrix};
+use crate::port::PortWrite;
+#[cfg(feature = "nrf52833")]
+use crate::port::{NrfColorPort, NrfOutputPin};
+
+/// Same as the scheduler, or a single tick if the time driver is slower than 1 MHz
+const DEFAULT_BCM_BASE_PERIOD: Duration = Duration::from_micros(1);
+
+/// Signaled with the frame count after each fully displayed frame
+pub type FrameSignal = Signal<CriticalSectionRawMutex, u32>;
+
+/// Return `None` if the presenter of `exchange` was already taken.
+pub fn split<
+    const LINECTRL_PIN_COUNT: usize,
+    const WIDTH: usize,
+    const HEIGHT: usize,
+    PORT: PortWrite,
+    PIN: OutputPin,
+>(
+    led_matrix: LedMatrix<LINECTRL_PIN_COUNT, WIDTH, HEIGHT, PORT, PIN>,
+    exchange: &'static FrameExchange<WIDTH, HEIGHT>,
+    frame_signal: &'static FrameSignal,
+) -> Option<(
+    MatrixRunner<LINECTRL_PIN_COUNT, WIDTH, HEIGHT, PORT, PIN>,
+    AsyncFramePresenter<WIDTH, HEIGHT>,
+)> {
+    let presenter =
+        exchange.take_presenter(*led_matrix.color_correction(), led_matrix.scan_mapping())?;
+    let runner = MatrixRunner {
+        led_matrix,
+        exchange,
+        frame_signal,
+        bcm_planes_nb: ColorBitPosition::MSB_POSITION + 1,
+        bcm_base_period_ticks: DEFAULT_BCM_BASE_PERIOD.as_ticks(),
+        brightness: u8::MAX,
+        frame_count: 0,
+    };
+    let presenter = AsyncFramePresenter {
+        presenter,
+        frame_signal,
+    };
+    Some((runner, presenter))
+}
+
+pub struct MatrixRunner<
+    #[cfg(feature = "nrf52833")] const LINECTRL_PIN_COUNT: usize = 4,
+    #[cfg(feature = "nrf52833")] const WIDTH: usize = 64,
+    #[cfg(feature = "nrf52833")] const HEIGHT: usize = 32,
+    #[cfg(feature = "nrf52833")] PORT = NrfColorPort,
+    #[cfg(feature = "nrf52833")] PIN = NrfOutputPin,
+    #[cfg(not(feature = "nrf52833"))] const LINECTRL_PIN_COUNT: usize,
+    #[cfg(not(feature = "nrf52833"))] const WIDTH: usize,
+    #[cfg(not(feature = "nrf52833"))] const HEIGHT: usize,
+    #[cfg(not(feature = "nrf52833"))] PORT,
+    #[cfg(not(feature = "nrf52833"))] PIN,
+> {
+    led_matrix: LedMatrix<LINECTRL_PIN_COUNT, WIDTH, HEIGHT, PORT, PIN>,
+    exchange: &'static FrameExchange<WIDTH, HEIGHT>,
+    frame_signal: &'static FrameSignal,
+    bcm_planes_nb: u8,
+    /// Display duration of the least significant displayed bit, in ticks of the time driver
+    bcm_base_period_ticks: u64,
+    brightness: u8,
+    frame_count: u32,
+}
+
+impl<
+        const LINECTRL_PIN_COUNT: usize,
+        const WIDTH: usize,
+        const HEIGHT: usize,
+        PORT: PortWrite,
+        PIN: OutputPin,
+    > MatrixRunner<LINECTRL_PIN_COUNT, WIDTH, HEIGHT, PORT, PIN>
+{
+    /// See [`ScheduledLedMatrix::set_bcm_planes_nb`](crate::scheduler::ScheduledLedMatrix::set_bcm_planes_nb)
+    pub fn set_bcm_planes_nb(&mut self, bcm_planes_nb: u8) {
+        self.bcm_planes_nb = bcm_planes_nb.clamp(1, ColorBitPosition::MSB_POSITION + 1);
+    }
+
+    /// Rounded down to whole ticks of the time driver, each next bit doubling it.
+    /// Fail if it is shorter than a tick.
+    pub fn set_bcm_base_period_microsec(&mut self, period_microsec: u32) -> Result<()> {
+        let ticks = Duration::from_micros_floor(period_microsec as u64).as_ticks();
+        if ticks == 0 {
+            return Err(Error::TimerPeriod);
+        }
+        self.bcm_base_period_ticks = ticks;
+        Ok(())
+    }
+
+    /// See [`ScheduledLedMatrix::set_brightness`](crate::scheduler::ScheduledLedMatrix::set_brightness).
+    /// The lit part of the slots is also in whole ticks, so dimming needs a base
+    /// period of several ticks.
+    pub fn set_brightness(&mut self, brightness: u8) {
+        self.brightness = brightness;
+    }
+
+    /// Scan the panel line after line, each line being displayed for all its
+    /// bit planes. Only return if a pin fails, the LEDs being then switched off.
+    pub async fn run(mut self) -> Error {
+        self.exchange.set_scanning(true);
+        let Err(error) = self.scan().await;
+        let _ = self.led_matrix.disable_output();
+        // The presenter waiting for the end of a frame is woken up, and does not wait anymore
+        self.exchange.set_scanning(false);
+        self.frame_signal.signal(self.frame_count);
+        error
+    }
+
+    async fn scan(&mut self) -> Result<core::convert::Infallible> {
+        self.led_matrix.init_panel_chip()?;
+        // Only the `bcm_planes_nb` most significant bits are displayed
+        let lowest_bit_position = ColorBitPosition::MSB_POSITION + 1 - self.bcm_planes_nb;
+        loop {
+            for line in 0..self.led_matrix.scan_lines() {
+                for cycle in 0..self.bcm_planes_nb {
+                    self.led_matrix.draw_frame_line(
+                        self.exchange.front_frame(),
+                        line,
+                        ColorBitPosition(lowest_bit_position + cycle),
+                    )?;
+                    self.wait_slot(cycle).await?;
+                }
+            }
+            self.exchange.swap_pending();
+            self.frame_count = self.frame_count.wrapping_add(1);
+            self.frame_signal.signal(self.frame_count);
+        }
+    }
+
+    async fn wait_slot(&mut self, cycle: u8) -> Result<()> {
+        let (lit_ticks, dark_ticks) =
+            slot_ticks(self.bcm_base_period_ticks, cycle, self.brightness);
+        if lit_ticks > 0 {
+            Timer::after_ticks(lit_ticks).await;
+        }
+        if dark_ticks > 0 {
+            self.led_matrix.disable_output()?;
+            Timer::after_ticks(dark_ticks).await;
+        }
+        Ok(())
+    }
+}
+
+/// Lit and dark parts of the slot of the `cycle`th displayed bit plane. Both
+/// are the ones of the first slot shifted by `cycle`, so each bit plane lasts
+/// exactly twice the previous one.
+fn slot_ticks(base_ticks: u64, cycle: u8, brightness: u8) -> (u64, u64) {
+    let lit_ticks = base_ticks * brightness as u64 / u8::MAX as u64;
+    (lit_ticks << cycle, (base_ticks - lit_ticks) << cycle)
+}
+
+/// Application side of a [`MatrixRunner`]
+pub struct AsyncFramePresenter<const WIDTH: usize, const HEIGHT: usize> {
+    presenter: FramePresenter<WIDTH, HEIGHT>,
+    frame_signal: &'static FrameSignal,
+}
+impl<const WIDTH: usize, const HEIGHT: usize> AsyncFramePresenter<WIDTH, HEIGHT> {
+    /// See [`FramePresenter::try_present`]
+    pub fn try_present(&mut self, canvas: &Canvas<WIDTH, HEIGHT>) -> bool {
+        self.presenter.try_present(canvas)
+    }
+
+    /// Queue `canvas`, waiting for the end of the displayed frame if the previous
+    /// presented canvas is still queued. With triple buffering, it replaces the
+    /// queued canvas instead, and never waits. Without a running [`MatrixRunner`],
+    /// the canvas is displayed as soon as the runner starts, without waiting.
+    pub async fn present(&mut self, canvas: &Canvas<WIDTH, HEIGHT>) {
+        while !self.presenter.try_present(canvas) && !cfg!(feature = "triple-buffering") {
+            self.frame_signal.wait().await;
+        }
+    }
+
+    /// Wait for the end of a frame, and return the frame count.
+    pub async fn wait_frame(&self) -> u32 {
+        self.frame_signal.wait().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slots_double_with_each_bit_plane() {
+        for base_ticks in [1, 3, 32] {
+            for brightness in [0, 1, 100, 128, u8::MAX] {
+                let (first_lit, first_dark) = slot_ticks(base_ticks, 0, brightness);
+                assert_eq!(first_lit + first_dark, base_ticks);
+                for cycle in 1..8 {
+                    let (lit, dark) = slot_ticks(base_ticks, cycle, brightness);
+                    assert_eq!(lit, first_lit << cycle);
+                    assert_eq!(dark, first_dark << cycle);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn full_and_zero_brightness_slots() {
+        assert_eq!(slot_ticks(3, 7, u8::MAX), (3 << 7, 0));
+        assert_eq!(slot_ticks(3, 7, 0), (0, 3 << 7));
+    }
+}
@@ -0,0 +1,50 @@
+/*
+MIT License
+
+Copyright (c) 2026 Vincent Hiribarren
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Errors reported by the library, instead of panicking.
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Error {
+    /// The shared scheduled matrix is already initialized
+    AlreadyInitialized,
+    /// The shared scheduled matrix is not initialized, or has been released
+    NotInitialized,
+    /// The level of a pin could not be changed
+    Pin,
+    /// A frame presenter is taken, and would convert the canvases with the previous settings
+    PresenterTaken,
+    /// The BCM base period is shorter than the timer allows, or its longest slot
+    /// does not fit in the timer counter
+    TimerPeriod,
+}
+
+pub type Result<T> = core::result::Result<T, Error>;
+
+impl Error {
+    /// To map the errors of the pins, which are optimized out for infallible pins
+    #[inline(always)]
+    pub(crate) fn pin<E>(_: E) -> Self {
+        Error::Pin
+    }
+}
@@ -0,0 +1,294 @@
+/*
+MIT License
+
+Copyright (c) 2026 Vincent Hiribarren
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Lock-free handoff of the frames between the application and the scan interrupt.
+//!
+//! With the [`ScheduledLedMatrix`](crate::scheduler::ScheduledLedMatrix) methods,
+//! everything is done in a critical section, the interrupt displaying the panel
+//! waiting meanwhile. Converting a canvas takes a while, which glitches the
+//! display. A [`FrameExchange`] holds the frame buffers, shared through an
+//! atomic index: the application draws and converts its canvas without
+//! disabling the interrupts, and only publishes the converted frame, which is
+//! displayed once the current frame ends.
+//!
+//!     let mut presenter = with_scheduled_matrix(scheduled_led_matrix, |led_matrix| {
+//!         led_matrix.take_presenter().unwrap()
+//!     })?;
+//!     loop {
+//!         canvas.draw_text(...);
+//!         presenter.present(&canvas);
+//!     }
+
+use core::cell::UnsafeCell;
+use core::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+
+use crate::bitplane::BitPlaneFrame;
+use crate::canvas::Canvas;
+use crate::gamma::ColorCorrection;
+use crate::scan::ScanMapping;
+
+/// Displayed frame, frame waiting for the end of the displayed one, and with
+/// triple buffering, a third one to convert the next canvas without waiting.
+#[cfg(not(feature = "triple-buffering"))]
+pub const FRAME_BUFFERS_NB: usize = 2;
+#[cfg(feature = "triple-buffering")]
+pub const FRAME_BUFFERS_NB: usize = 3;
+
+/// Displayed frame index on the low nibble, queued frame index on the high one
+#[derive(Copy, Clone)]
+struct ExchangeState {
+    front: usize,
+    pending: Option<usize>,
+}
+
+impl ExchangeState {
+    const NO_PENDING: u8 = 0x0F;
+
+    const fn decode(value: u8) -> Self {
+        let pending = value >> 4;
+        ExchangeState {
+            front: (value & 0x0F) as usize,
+            pending: if pending == Self::NO_PENDING {
+                None
+            } else {
+                Some(pending as usize)
+            },
+        }
+    }
+
+    const fn encode(self) -> u8 {
+        let pending = match self.pending {
+            Some(pending) => pending as u8,
+            None => Self::NO_PENDING,
+        };
+        (pending << 4) | self.front as u8
+    }
+}
+
+pub struct FrameExchange<const WIDTH: usize, const HEIGHT: usize> {
+    frames: [UnsafeCell<BitPlaneFrame<WIDTH, HEIGHT>>; FRAME_BUFFERS_NB],
+    state: AtomicU8,
+    presenter_taken: AtomicBool,
+    /// Set while a scan displays the frames and swaps the queued one at the end of each frame
+    scanning: AtomicBool,
+}
+
+// The interrupt only reads the front frame, the presenter only writes the
+// frames which are neither displayed nor queued, and there is one presenter.
+unsafe impl<const WIDTH: usize, const HEIGHT: usize> Sync for FrameExchange<WIDTH, HEIGHT> {}
+
+impl<const WIDTH: usize, const HEIGHT: usize> Default for FrameExchange<WIDTH, HEIGHT> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const WIDTH: usize, const HEIGHT: usize> FrameExchange<WIDTH, HEIGHT> {
+    /// All the frames with the LEDs off
+    pub const fn new() -> Self {
+        let state = ExchangeState {
+            front: 0,
+            pending: None,
+        };
+        FrameExchange {
+            frames: [const { UnsafeCell::new(BitPlaneFrame::new()) }; FRAME_BUFFERS_NB],
+            state: AtomicU8::new(state.encode()),
+            presenter_taken: AtomicBool::new(false),
+            scanning: AtomicBool::new(false),
+        }
+    }
+
+    /// Only the first call returns the presenter, there is a single one.
+    pub fn take_presenter(
+        &'static self,
+        color_correction: ColorCorrection,
+        scan_mapping: &'static dyn ScanMapping<WIDTH, HEIGHT>,
+    ) -> Option<FramePresenter<WIDTH, HEIGHT>> {
+        if self.presenter_taken.swap(true, Ordering::AcqRel) {
+            return None;
+        }
+        Some(FramePresenter {
+            exchange: self,
+            color_correction,
+            scan_mapping,
+        })
+    }
+
+    /// Frame to display, only to use from the scan interrupt.
+    pub(crate) fn front_frame(&self) -> &BitPlaneFrame<WIDTH, HEIGHT> {
+        let state = ExchangeState::decode(self.state.load(Ordering::Acquire));
+        // Safety: the presenter never writes the front frame
+        unsafe { &*self.frames[state.front].get() }
+    }
+
+    /// Frame being displayed, to convert a canvas displayed right away.
+    ///
+    /// Safety: the front frame must not be displayed meanwhile, nor referenced elsewhere.
+    #[cfg(feature = "nrf52833")]
+    #[allow(clippy::mut_from_ref)]
+    pub(crate) unsafe fn front_frame_mut(&self) -> &mut BitPlaneFrame<WIDTH, HEIGHT> {
+        let state = ExchangeState::decode(self.state.load(Ordering::Acquire));
+        &mut *self.frames[state.front].get()
+    }
+
+    /// Forget the queued frame, if any, which then becomes free again.
+    #[cfg(feature = "nrf52833")]
+    pub(crate) fn drop_pending(&self) {
+        let _ = self
+            .state
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |value| {
+                let state = ExchangeState::decode(value);
+                Some(
+                    ExchangeState {
+                        front: state.front,
+                        pending: None,
+                    }
+                    .encode(),
+                )
+            });
+    }
+
+    /// True while a presented frame waits for the end of the displayed one
+    pub fn is_present_pending(&self) -> bool {
+        ExchangeState::decode(self.state.load(Ordering::Acquire))
+            .pending
+            .is_some()
+    }
+
+    /// Without a scan, nothing waits for the end of a frame: the presented
+    /// frames become the front one right away.
+    pub(crate) fn set_scanning(&self, scanning: bool) {
+        self.scanning.store(scanning, Ordering::Release);
+        if !scanning {
+            self.swap_pending();
+        }
+    }
+
+    pub fn is_scanning(&self) -> bool {
+        self.scanning.load(Ordering::Acquire)
+    }
+
+    /// Display the queued frame, if any, to call at the end of a frame.
+    pub(crate) fn swap_pending(&self) {
+        let _ = self
+            .state
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |value| {
+                let state = ExchangeState::decode(value);
+                state.pending.map(|pending| {
+                    ExchangeState {
+                        front: pending,
+                        pending: None,
+                    }
+                    .encode()
+                })
+            });
+    }
+}
+
+/// Application side of a [`FrameExchange`], obtained with
+/// [`ScheduledLedMatrix::take_presenter`](crate::scheduler::ScheduledLedMatrix::take_presenter).
+///
+/// The color correction and the scan mapping are the ones of the scheduled
+/// matrix when taken, which then rejects another scan mapping.
+pub struct FramePresenter<const WIDTH: usize, const HEIGHT: usize> {
+    exchange: &'static FrameExchange<WIDTH, HEIGHT>,
+    color_correction: ColorCorrection,
+    scan_mapping: &'static dyn ScanMapping<WIDTH, HEIGHT>,
+}
+
+impl<const WIDTH: usize, const HEIGHT: usize> FramePresenter<WIDTH, HEIGHT> {
+    /// Same as [`ScheduledLedMatrix::try_present`](crate::scheduler::ScheduledLedMatrix::try_present),
+    /// without disabling the interrupts.
+    pub fn try_present(&mut self, canvas: &Canvas<WIDTH, HEIGHT>) -> bool {
+        let state = ExchangeState::decode(self.exchange.state.load(Ordering::Acquire));
+        // The interrupt can only move the queued frame to the front, so a free frame stays free
+        let Some(free) =
+            (0..FRAME_BUFFERS_NB).find(|&idx| idx != state.front && Some(idx) != state.pending)
+        else {
+            return false;
+        };
+        // Safety: the frame is neither displayed nor queued, and only this presenter queues frames
+        let frame = unsafe { &mut *self.exchange.frames[free].get() };
+        frame.update_from_canvas(canvas, &self.color_correction, self.scan_mapping);
+        let previous = self
+            .exchange
+            .state
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |value| {
+                let state = ExchangeState::decode(value);
+                Some(
+                    ExchangeState {
+                        front: state.front,
+                        pending: Some(free),
+                    }
+                    .encode(),
+                )
+            })
+            .unwrap();
+        if !self.exchange.is_scanning() {
+            self.exchange.swap_pending();
+        }
+        ExchangeState::decode(previous).pending.is_none()
+    }
+
+    /// Same as [`scheduler::present`](crate::scheduler::present), without disabling the interrupts.
+    /// Never waits if the frames are not scanned, the canvas being then the front one.
+    pub fn present(&mut self, canvas: &Canvas<WIDTH, HEIGHT>) {
+        while !self.try_present(canvas) && !cfg!(feature = "triple-buffering") {
+            if !self.exchange.is_scanning() {
+                // Stopped meanwhile, the queued frame will never be swapped by the scan
+                self.exchange.swap_pending();
+                continue;
+            }
+            // Woken up by the scan interrupt, the frame is swapped by one of them
+            cortex_m::asm::wfi();
+        }
+    }
+
+    pub fn is_present_pending(&self) -> bool {
+        self.exchange.is_present_pending()
+    }
+
+    pub fn set_color_correction(&mut self, color_correction: ColorCorrection) {
+        self.color_correction = color_correction;
+    }
+
+    /// Only for the scheduled matrix, which also changes the mapping of its scan
+    #[cfg(feature = "nrf52833")]
+    pub(crate) fn set_scan_mapping(
+        &mut self,
+        scan_mapping: &'static dyn ScanMapping<WIDTH, HEIGHT>,
+    ) {
+        self.scan_mapping = scan_mapping;
+    }
+}
+
+impl<const WIDTH: usize, const HEIGHT: usize> Drop for FramePresenter<WIDTH, HEIGHT> {
+    /// The presenter can be taken again, for instance by a scheduled matrix
+    /// filling a released static again.
+    fn drop(&mut self) {
+        self.exchange
+            .presenter_taken
+            .store(false, Ordering::Release);
+    }
+}
@@ -1233,3 +1233,59 @@ impl Font14x32 {
         [0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0],
     ]);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DIGITS: [char; 10] = ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'];
+
+    fn is_empty<const W: usize, const H: usize>(stencil: &Stencil<W, H>) -> bool {
+        stencil.0.iter().flatten().all(|&bit| bit == 0)
+    }
+
+    #[test]
+    fn space_is_empty() {
+        assert!(is_empty(Font5x7.stencil_for(' ')));
+        assert!(is_empty(Font8x16.stencil_for(' ')));
+        assert!(is_empty(Font14x32.stencil_for(' ')));
+    }
+
+    #[test]
+    fn unknown_chars_use_any() {
+        assert_eq!(Font5x7.stencil_for('\u{e9}').0, Font5x7::ANY.0);
+        assert_eq!(Font8x16.stencil_for('x').0, Font8x16::ANY.0);
+        assert_eq!(Font14x32.stencil_for('x').0, Font14x32::ANY.0);
+    }
+
+    #[test]
+    fn letters_ignore_case() {
+        for (lower, upper) in ('a'..='z').zip('A'..='Z') {
+            assert_eq!(Font5x7.stencil_for(lower).0, Font5x7.stencil_for(upper).0);
+            assert_ne!(Font5x7.stencil_for(upper).0, Font5x7::ANY.0, "{upper}");
+        }
+    }
+
+    #[test]
+    fn digits_are_distinct_glyphs() {
+        for (idx, &digit) in DIGITS.iter().enumerate() {
+            let stencil_5x7 = Font5x7.stencil_for(digit);
+            let stencil_8x16 = Font8x16.stencil_for(digit);
+            assert!(!is_empty(stencil_5x7) && !is_empty(stencil_8x16), "{digit}");
+            assert_ne!(stencil_5x7.0, Font5x7::ANY.0, "{digit}");
+            assert_ne!(stencil_8x16.0, Font8x16::ANY.0, "{digit}");
+            for &other in &DIGITS[idx + 1..] {
+                assert_ne!(
+                    stencil_5x7.0,
+                    Font5x7.stencil_for(other).0,
+                    "{digit} {other}"
+                );
+                assert_ne!(
+                    stencil_8x16.0,
+                    Font8x16.stencil_for(other).0,
+                    "{digit} {other}"
+                );
+            }
+        }
+    }
+}
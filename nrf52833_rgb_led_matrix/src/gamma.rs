@@ -0,0 +1,238 @@
+/*
+MIT License
+
+Copyright (c) 2026 Vincent Hiribarren
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Color correction applied between the canvas and the pins of the panel.
+//!
+//! The LEDs brightness is linear with the BCM duty cycle, while the eye is
+//! not; a gamma curve gives back some depth to the low intensities. Each
+//! channel has its own table, which also allows to fix the color balance of a
+//! panel. Tables are computed at compile time when declared as `const`:
+//!
+//! ```rust
+//! # use nrf52833_rgb_led_matrix::gamma::ColorCorrection;
+//! const CORRECTION: ColorCorrection = ColorCorrection::from_gammas(2.2, 2.0, 2.4);
+//! ```
+
+use crate::canvas::Color;
+
+pub const DEFAULT_GAMMA: f32 = 2.2;
+
+/// Lookup table for the values of one color channel
+#[derive(Copy, Clone, PartialEq)]
+pub struct ChannelLut(pub [u8; 256]);
+
+impl ChannelLut {
+    pub const IDENTITY: ChannelLut = ChannelLut::identity();
+
+    const fn identity() -> Self {
+        let mut table = [0; 256];
+        let mut idx = 0;
+        while idx < 256 {
+            table[idx] = idx as u8;
+            idx += 1;
+        }
+        ChannelLut(table)
+    }
+
+    /// `value = 255 * (input / 255) ^ gamma`
+    pub const fn from_gamma(gamma: f32) -> Self {
+        let mut table = [0; 256];
+        let mut idx = 1;
+        while idx < 256 {
+            let corrected = 255.0 * pow(idx as f64 / 255.0, gamma as f64);
+            table[idx] = (corrected + 0.5) as u8;
+            idx += 1;
+        }
+        ChannelLut(table)
+    }
+
+    #[inline(always)]
+    pub const fn apply(&self, value: u8) -> u8 {
+        self.0[value as usize]
+    }
+}
+
+impl Default for ChannelLut {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Default)]
+pub struct ColorCorrection {
+    pub r: ChannelLut,
+    pub g: ChannelLut,
+    pub b: ChannelLut,
+}
+
+impl ColorCorrection {
+    /// No correction, the canvas values are directly used
+    pub const IDENTITY: ColorCorrection = ColorCorrection::from_luts(
+        ChannelLut::IDENTITY,
+        ChannelLut::IDENTITY,
+        ChannelLut::IDENTITY,
+    );
+
+    pub const DEFAULT_GAMMA: ColorCorrection = ColorCorrection::from_gamma(DEFAULT_GAMMA);
+
+    pub const fn from_luts(r: ChannelLut, g: ChannelLut, b: ChannelLut) -> Self {
+        ColorCorrection { r, g, b }
+    }
+
+    pub const fn from_gamma(gamma: f32) -> Self {
+        Self::from_gammas(gamma, gamma, gamma)
+    }
+
+    pub const fn from_gammas(gamma_r: f32, gamma_g: f32, gamma_b: f32) -> Self {
+        ColorCorrection {
+            r: ChannelLut::from_gamma(gamma_r),
+            g: ChannelLut::from_gamma(gamma_g),
+            b: ChannelLut::from_gamma(gamma_b),
+        }
+    }
+
+    #[inline(always)]
+    pub fn apply(&self, color: &Color) -> Color {
+        Color::new(
+            self.r.apply(color.r()),
+            self.g.apply(color.g()),
+            self.b.apply(color.b()),
+        )
+    }
+}
+
+// `f64::powf` is neither available in `core`, nor usable in a const context,
+// so here are some minimal versions, only valid for the ranges used above.
+
+const LN_2: f64 = core::f64::consts::LN_2;
+
+/// `x ^ y`, for `x` in ]0, 1] and `y` positive
+const fn pow(x: f64, y: f64) -> f64 {
+    exp(y * ln(x))
+}
+
+/// Natural logarithm, for `x` in ]0, 1]
+const fn ln(x: f64) -> f64 {
+    // Bring x in [0.5, 1], where the series below converges quickly
+    let mut x = x;
+    let mut exponent = 0.0;
+    while x < 0.5 {
+        x *= 2.0;
+        exponent += 1.0;
+    }
+    // ln(x) = 2 * atanh((x - 1) / (x + 1))
+    let z = (x - 1.0) / (x + 1.0);
+    let z_square = z * z;
+    let mut term = z;
+    let mut sum = 0.0;
+    let mut n = 1.0;
+    while n < 60.0 {
+        sum += term / n;
+        term *= z_square;
+        n += 2.0;
+    }
+    2.0 * sum - exponent * LN_2
+}
+
+/// Exponential, for `x` negative or null
+const fn exp(x: f64) -> f64 {
+    // exp(x) = exp(x / 2^k) ^ (2^k), with x / 2^k small enough for the Taylor series
+    let mut x = x;
+    let mut squaring_nb = 0;
+    while x < -0.5 {
+        x /= 2.0;
+        squaring_nb += 1;
+    }
+    let mut term = 1.0;
+    let mut sum = 1.0;
+    let mut n = 1.0;
+    while n < 20.0 {
+        term *= x / n;
+        sum += term;
+        n += 1.0;
+    }
+    while squaring_nb > 0 {
+        sum *= sum;
+        squaring_nb -= 1;
+    }
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GAMMAS: [f32; 5] = [1.0, 1.8, DEFAULT_GAMMA, 2.4, 2.8];
+
+    /// Same formula as `ChannelLut::from_gamma`, with the `std` power function
+    fn expected(input: usize, gamma: f32) -> f64 {
+        255.0 * (input as f64 / 255.0).powf(gamma as f64)
+    }
+
+    #[test]
+    fn lut_keeps_the_endpoints() {
+        for gamma in GAMMAS {
+            let lut = ChannelLut::from_gamma(gamma);
+            assert_eq!(lut.apply(0), 0, "gamma {gamma}");
+            assert_eq!(lut.apply(255), 255, "gamma {gamma}");
+        }
+    }
+
+    #[test]
+    fn lut_is_monotonic() {
+        for gamma in GAMMAS {
+            let lut = ChannelLut::from_gamma(gamma);
+            assert!(
+                lut.0.windows(2).all(|pair| pair[0] <= pair[1]),
+                "gamma {gamma}"
+            );
+        }
+    }
+
+    #[test]
+    fn lut_matches_powf() {
+        for gamma in GAMMAS {
+            let lut = ChannelLut::from_gamma(gamma);
+            for (input, &value) in lut.0.iter().enumerate() {
+                let error = (value as f64 - expected(input, gamma)).abs();
+                // Only the rounding to the nearest integer
+                assert!(error <= 0.5 + 1e-9, "gamma {gamma}, input {input}: {value}");
+            }
+        }
+    }
+
+    #[test]
+    fn gamma_one_is_the_identity() {
+        assert!(ChannelLut::from_gamma(1.0) == ChannelLut::IDENTITY);
+    }
+
+    #[test]
+    fn color_correction_applies_each_channel_lut() {
+        let correction = ColorCorrection::from_gammas(1.0, 2.0, 3.0);
+        let color = correction.apply(&Color::new(128, 128, 128));
+        assert_eq!(color.r(), 128);
+        assert_eq!(color.g(), expected(128, 2.0).round() as u8);
+        assert_eq!(color.b(), expected(128, 3.0).round() as u8);
+    }
+}
@@ -24,11 +24,18 @@ SOFTWARE.
 
 use nrf52833_hal::gpio::{self, Disconnected, Pin};
 
-use nrf52833_hal::pac::{Peripherals, CLOCK, P0, P1, RTC2, TIMER4};
-
-use crate::ledmatrix::{LedMatrix, LedMatrixPins64x32};
-use crate::scheduler::{ScheduledLedMatrix, SharedScheduledMatrix64x32};
-use crate::timer::Timer16Mhz;
+#[cfg(feature = "builtin-scheduler")]
+use nrf52833_hal::pac::{Peripherals, CLOCK, RTC2, TIMER4};
+use nrf52833_hal::pac::{P0, P1};
+
+use crate::ledmatrix::{LedMatrixPins64x32, LedMatrixPins64x64};
+#[cfg(feature = "builtin-scheduler")]
+use crate::{
+    error::Result,
+    ledmatrix::LedMatrix,
+    scheduler::{with_scheduled_matrix, ScheduledLedMatrix, SharedScheduledPanel},
+    timer::Timer16Mhz,
+};
 
 ///! Helpers to launch examples without repeating to much code.
 
@@ -46,6 +53,7 @@ macro_rules! register_panic_handler_with_logging {
     };
 }
 
+#[cfg(feature = "builtin-scheduler")]
 #[macro_export]
 macro_rules! init_scheduled_led_matrix_system {
     ($peripherals:ident) => {
@@ -59,18 +67,39 @@ macro_rules! init_scheduled_led_matrix_system {
     };
 }
 
-pub fn init_scheduled_led_matrix_system(p: Peripherals) -> &'static SharedScheduledMatrix64x32 {
+#[cfg(feature = "builtin-scheduler")]
+pub fn init_scheduled_led_matrix_system(p: Peripherals) -> &'static SharedScheduledPanel {
     init_scheduled_led_matrix_system_from_parts(p.P0, p.P1, p.TIMER4, p.CLOCK, p.RTC2)
 }
 
-#[allow(unused_variables)]
+#[cfg(feature = "builtin-scheduler")]
+pub fn try_init_scheduled_led_matrix_system(
+    p: Peripherals,
+) -> Result<&'static SharedScheduledPanel> {
+    try_init_scheduled_led_matrix_system_from_parts(p.P0, p.P1, p.TIMER4, p.CLOCK, p.RTC2)
+}
+
+/// Panic if the built-in scheduled matrix is already initialized.
+#[cfg(feature = "builtin-scheduler")]
 pub fn init_scheduled_led_matrix_system_from_parts(
     p0: P0,
     p1: P1,
     timer4: TIMER4,
     clock: CLOCK,
     rtc2: RTC2,
-) -> &'static SharedScheduledMatrix64x32 {
+) -> &'static SharedScheduledPanel {
+    try_init_scheduled_led_matrix_system_from_parts(p0, p1, timer4, clock, rtc2).unwrap()
+}
+
+#[cfg(feature = "builtin-scheduler")]
+#[allow(unused_variables)]
+pub fn try_init_scheduled_led_matrix_system_from_parts(
+    p0: P0,
+    p1: P1,
+    timer4: TIMER4,
+    clock: CLOCK,
+    rtc2: RTC2,
+) -> Result<&'static SharedScheduledPanel> {
     #[cfg(feature = "logging")]
     {
         use crate::metrics::*;
@@ -78,17 +107,17 @@ pub fn init_scheduled_led_matrix_system_from_parts(
         init_debug_metrics(timer_source);
     }
 
-    let pins = MicrobitPinMapFor64x32::new(p0, p1);
-    let led_matrix = LedMatrix::new(pins.led_matrix);
-    let scheduled_led_matrix = ScheduledLedMatrix::take_ref(led_matrix, Timer16Mhz::new(timer4));
-
-    cortex_m::interrupt::free(|cs| {
-        let mut borrowed_scheduled_led_matrix = scheduled_led_matrix.borrow(cs).borrow_mut();
-        let led_matrix = borrowed_scheduled_led_matrix.as_mut().unwrap();
-        led_matrix.start_rendering_loop();
-    });
-
-    scheduled_led_matrix
+    #[cfg(not(feature = "panel-64x64"))]
+    let led_matrix = LedMatrix::new(MicrobitPinMapFor64x32::new(p0, p1).led_matrix);
+    #[cfg(feature = "panel-64x64")]
+    let led_matrix = LedMatrix::new_64x64(MicrobitPinMapFor64x64::new(p0, p1).led_matrix);
+    let scheduled_led_matrix =
+        ScheduledLedMatrix::try_take_ref(led_matrix, Timer16Mhz::new(timer4))?;
+    with_scheduled_matrix(scheduled_led_matrix, |led_matrix| {
+        led_matrix.start_rendering_loop()
+    })??;
+
+    Ok(scheduled_led_matrix)
 }
 
 pub struct MicrobitPinMapFor64x32 {
@@ -131,3 +160,41 @@ impl MicrobitPinMapFor64x32 {
         }
     }
 }
+
+pub struct MicrobitPinMapFor64x64 {
+    pub led_matrix: LedMatrixPins64x64<Disconnected>,
+    pub button_a: Pin<Disconnected>,
+    pub button_b: Pin<Disconnected>,
+}
+
+/// Same as [`MicrobitPinMapFor64x32`], with the additional address line:
+/// P3 -> e
+impl MicrobitPinMapFor64x64 {
+    pub fn new(p0: P0, p1: P1) -> Self {
+        let p0_parts = gpio::p0::Parts::new(p0);
+        let p1_parts = gpio::p1::Parts::new(p1);
+        let led_matrix = LedMatrixPins64x64 {
+            r1: p0_parts.p0_02.into(),
+            g1: p0_parts.p0_03.into(),
+            b1: p0_parts.p0_04.into(),
+            r2: p0_parts.p0_11.into(),
+            g2: p0_parts.p0_10.into(),
+            b2: p0_parts.p0_09.into(),
+            a: p0_parts.p0_12.into(),
+            b: p0_parts.p0_17.into(),
+            c: p0_parts.p0_01.into(),
+            d: p0_parts.p0_13.into(),
+            e: p0_parts.p0_31.into(),
+            clk: p1_parts.p1_02.into(),
+            oe: p0_parts.p0_26.into(),
+            lat: p1_parts.p1_00.into(),
+        };
+        let button_a = p0_parts.p0_14.into();
+        let button_b = p0_parts.p0_23.into();
+        Self {
+            led_matrix,
+            button_a,
+            button_b,
+        }
+    }
+}
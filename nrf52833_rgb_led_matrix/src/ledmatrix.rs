@@ -25,28 +25,44 @@ SOFTWARE.
 //! If there are not regular switch between two elements of A, B, C or D, the panel shutdown.
 //! A minimal block like this one is needed to allow the system working:
 //!
-//!     loop {
-//!          pin_a.set_low().unwrap();
-//!          pin_a.set_high().unwrap();
-//!     }
+//! ```ignore
+//! loop {
+//!      pin_a.set_low().unwrap();
+//!      pin_a.set_high().unwrap();
+//! }
+//! ```
 //!
 //! Correct order is:
 //!
-//!     for _ in range(64):
-//!         select colors
-//!         clock it, H then L
-//!     set OE to H
-//!     select A, B, C, D
-//!     set latch to H
-//!     set latch to L
-//!     set OE to L
-
-use crate::canvas::Canvas;
+//! ```text
+//! for _ in range(64):
+//!     select colors
+//!     clock it, H then L
+//! set OE to H
+//! select A, B, C, D
+//! set latch to H
+//! set latch to L
+//! set OE to L
+//! ```
+
+use crate::bitplane::BitPlaneFrame;
+use crate::canvas::{Canvas, Color};
+use crate::chip::PanelChip;
+use crate::error::{Error, Result};
+use crate::gamma::ColorCorrection;
+use crate::port::PortWrite;
+#[cfg(feature = "nrf52833")]
+use crate::port::{NrfColorPort, NrfOutputPin};
+use crate::scan::{HalfScan, PanelHalf, ScanMapping};
+#[cfg(feature = "nrf52833")]
 use crate::timer::Timer16Mhz;
+#[cfg(feature = "nrf52833")]
 use crate::MatrixTimer;
+use embedded_hal::digital::{OutputPin, PinState};
+#[cfg(feature = "nrf52833")]
 use nrf52833_hal::gpio::{Level, Output, Pin, PushPull};
-use nrf52833_hal::prelude::*;
 
+#[cfg(feature = "nrf52833")]
 pub struct LedMatrixPins64x32<MODE> {
     pub r1: Pin<MODE>,
     pub g1: Pin<MODE>,
@@ -63,6 +79,63 @@ pub struct LedMatrixPins64x32<MODE> {
     pub oe: Pin<MODE>,
 }
 
+#[cfg(feature = "nrf52833")]
+/// Pins of a 64x64 panel, which has a fifth address line, E
+pub struct LedMatrixPins64x64<MODE> {
+    pub r1: Pin<MODE>,
+    pub g1: Pin<MODE>,
+    pub b1: Pin<MODE>,
+    pub r2: Pin<MODE>,
+    pub g2: Pin<MODE>,
+    pub b2: Pin<MODE>,
+    pub a: Pin<MODE>,
+    pub b: Pin<MODE>,
+    pub c: Pin<MODE>,
+    pub d: Pin<MODE>,
+    pub e: Pin<MODE>,
+    pub clk: Pin<MODE>,
+    pub lat: Pin<MODE>,
+    pub oe: Pin<MODE>,
+}
+
+#[cfg(feature = "nrf52833")]
+/// Pins of a panel of any size, with `LINECTRL_PIN_COUNT` address lines
+pub struct LedMatrixPins<MODE, const LINECTRL_PIN_COUNT: usize> {
+    /// R1, G1, B1, R2, G2, B2
+    pub colors: [Pin<MODE>; COLOR_PIN_COUNT],
+    /// A, B, C...
+    pub line_ctrl: [Pin<MODE>; LINECTRL_PIN_COUNT],
+    pub clk: Pin<MODE>,
+    pub lat: Pin<MODE>,
+    pub oe: Pin<MODE>,
+}
+
+#[cfg(feature = "nrf52833")]
+impl<MODE> From<LedMatrixPins64x32<MODE>> for LedMatrixPins<MODE, 4> {
+    fn from(pins: LedMatrixPins64x32<MODE>) -> Self {
+        LedMatrixPins {
+            colors: [pins.r1, pins.g1, pins.b1, pins.r2, pins.g2, pins.b2],
+            line_ctrl: [pins.a, pins.b, pins.c, pins.d],
+            clk: pins.clk,
+            lat: pins.lat,
+            oe: pins.oe,
+        }
+    }
+}
+
+#[cfg(feature = "nrf52833")]
+impl<MODE> From<LedMatrixPins64x64<MODE>> for LedMatrixPins<MODE, 5> {
+    fn from(pins: LedMatrixPins64x64<MODE>) -> Self {
+        LedMatrixPins {
+            colors: [pins.r1, pins.g1, pins.b1, pins.r2, pins.g2, pins.b2],
+            line_ctrl: [pins.a, pins.b, pins.c, pins.d, pins.e],
+            clk: pins.clk,
+            lat: pins.lat,
+            oe: pins.oe,
+        }
+    }
+}
+
 /// 0 is LSB, 7 is MSB for a u8
 #[derive(Copy, Clone)]
 pub struct ColorBitPosition(pub u8);
@@ -79,108 +152,276 @@ impl Default for ColorBitPosition {
     }
 }
 
-const RGB_COUNT: usize = 3;
+/// R1, G1, B1, R2, G2, B2
+pub(crate) const COLOR_PIN_COUNT: usize = 6;
+
+/// Number of possible levels of the six color pins, for one bit position.
+pub(crate) const COLOR_WORD_COUNT: usize = 1 << COLOR_PIN_COUNT;
 
+/// Color word with all the color pins high
+const ALL_COLOR_PINS: u8 = (COLOR_WORD_COUNT - 1) as u8;
+
+/// Driver of a panel, the colors and the clock going through `PORT`, and the
+/// address lines, LAT and OE through `PIN`. By default, the nRF52833 ones.
 pub struct LedMatrix<
-    const LINECTRL_PIN_COUNT: usize = 4,
-    const WIDTH: usize = 64,
-    const HEIGHT: usize = 32,
+    #[cfg(feature = "nrf52833")] const LINECTRL_PIN_COUNT: usize = 4,
+    #[cfg(feature = "nrf52833")] const WIDTH: usize = 64,
+    #[cfg(feature = "nrf52833")] const HEIGHT: usize = 32,
+    #[cfg(feature = "nrf52833")] PORT = NrfColorPort,
+    #[cfg(feature = "nrf52833")] PIN = NrfOutputPin,
+    // Without default pins, the sizes cannot have a default either
+    #[cfg(not(feature = "nrf52833"))] const LINECTRL_PIN_COUNT: usize,
+    #[cfg(not(feature = "nrf52833"))] const WIDTH: usize,
+    #[cfg(not(feature = "nrf52833"))] const HEIGHT: usize,
+    #[cfg(not(feature = "nrf52833"))] PORT,
+    #[cfg(not(feature = "nrf52833"))] PIN,
 > {
-    top_colors: [Pin<Output<PushPull>>; RGB_COUNT],
-    bottom_colors: [Pin<Output<PushPull>>; RGB_COUNT],
-    pin_clk: Pin<Output<PushPull>>,
-    pin_lat: Pin<Output<PushPull>>,
-    pin_oe: Pin<Output<PushPull>>,
-    line_ctrl: [Pin<Output<PushPull>>; LINECTRL_PIN_COUNT],
+    port: PORT,
+    pin_lat: PIN,
+    pin_oe: PIN,
+    line_ctrl: [PIN; LINECTRL_PIN_COUNT],
+    color_correction: ColorCorrection,
+    scan_mapping: &'static dyn ScanMapping<WIDTH, HEIGHT>,
+    panel_chip: PanelChip,
+    /// Lines are still latched, but OE stays high
+    blanked: bool,
 }
 
+#[cfg(feature = "nrf52833")]
 impl LedMatrix<4, 64, 32> {
     pub fn new<MODE>(pins: LedMatrixPins64x32<MODE>) -> Self {
-        LedMatrix {
-            top_colors: [
-                pins.r1.into_push_pull_output(Level::Low),
-                pins.g1.into_push_pull_output(Level::Low),
-                pins.b1.into_push_pull_output(Level::Low),
-            ],
-            bottom_colors: [
-                pins.r2.into_push_pull_output(Level::Low),
-                pins.g2.into_push_pull_output(Level::Low),
-                pins.b2.into_push_pull_output(Level::Low),
-            ],
-            line_ctrl: [
-                pins.a.into_push_pull_output(Level::Low),
-                pins.b.into_push_pull_output(Level::Low),
-                pins.c.into_push_pull_output(Level::Low),
-                pins.d.into_push_pull_output(Level::Low),
-            ],
-            pin_clk: pins.clk.into_push_pull_output(Level::Low),
-            pin_lat: pins.lat.into_push_pull_output(Level::Low),
-            pin_oe: pins.oe.into_push_pull_output(Level::High),
-        }
+        Self::with_pins(pins.into())
     }
 }
 
+#[cfg(feature = "nrf52833")]
+impl LedMatrix<5, 64, 64> {
+    pub fn new_64x64<MODE>(pins: LedMatrixPins64x64<MODE>) -> Self {
+        Self::with_pins(pins.into())
+    }
+}
+
+#[cfg(feature = "nrf52833")]
 impl<const LINECTRL_PIN_COUNT: usize, const WIDTH: usize, const HEIGHT: usize>
     LedMatrix<LINECTRL_PIN_COUNT, WIDTH, HEIGHT>
 {
+    pub fn with_pins<MODE>(pins: LedMatrixPins<MODE, LINECTRL_PIN_COUNT>) -> Self {
+        let output = |pin: Pin<MODE>, level| NrfOutputPin(pin.into_push_pull_output(level));
+        LedMatrix::from_parts(
+            NrfColorPort::new(pins.colors, pins.clk),
+            pins.line_ctrl.map(|pin| output(pin, Level::Low)),
+            output(pins.lat, Level::Low),
+            output(pins.oe, Level::High),
+        )
+    }
+
+    /// Switch off the LEDs, and give back the pins, still configured as outputs.
+    pub fn release(self) -> LedMatrixPins<Output<PushPull>, LINECTRL_PIN_COUNT> {
+        let (port, line_ctrl, lat, oe) = self.into_parts();
+        let (colors, clk) = port.release();
+        LedMatrixPins {
+            colors,
+            line_ctrl: line_ctrl.map(|pin| pin.0),
+            clk,
+            lat: lat.0,
+            oe: oe.0,
+        }
+    }
+}
+
+impl<
+        const LINECTRL_PIN_COUNT: usize,
+        const WIDTH: usize,
+        const HEIGHT: usize,
+        PORT: PortWrite,
+        PIN: OutputPin,
+    > LedMatrix<LINECTRL_PIN_COUNT, WIDTH, HEIGHT, PORT, PIN>
+{
+    /// Panel driven by any MCU. The pins must already be outputs, with LAT low
+    /// and OE high, so the LEDs stay off until the first drawn line.
+    pub fn from_parts(port: PORT, line_ctrl: [PIN; LINECTRL_PIN_COUNT], lat: PIN, oe: PIN) -> Self {
+        LedMatrix {
+            port,
+            line_ctrl,
+            pin_lat: lat,
+            pin_oe: oe,
+            color_correction: ColorCorrection::IDENTITY,
+            scan_mapping: &HalfScan,
+            panel_chip: PanelChip::Generic,
+            blanked: false,
+        }
+    }
+
+    /// Color and clock pins, for instance to shift the lines with another peripheral.
+    pub fn port(&self) -> &PORT {
+        &self.port
+    }
+
+    /// Switch off the LEDs, and give back the color port, the address lines, LAT and OE.
+    pub fn into_parts(mut self) -> (PORT, [PIN; LINECTRL_PIN_COUNT], PIN, PIN) {
+        // The pins are given back even if OE could not be set
+        let _ = self.disable_output();
+        (self.port, self.line_ctrl, self.pin_lat, self.pin_oe)
+    }
+}
+
+/// Pack the bit at `bit_position` of each color channel, in the order of the
+/// color pins: bit 0 is R1, bit 1 is G1, ..., bit 5 is B2.
+#[inline(always)]
+pub(crate) fn color_word(top: &Color, bottom: &Color, bit_position: ColorBitPosition) -> usize {
+    let bit = bit_position.0;
+    let channel_bit = |channel: u8, pin_idx: usize| (((channel >> bit) & 0x01) as usize) << pin_idx;
+    channel_bit(top.r(), 0)
+        | channel_bit(top.g(), 1)
+        | channel_bit(top.b(), 2)
+        | channel_bit(bottom.r(), 3)
+        | channel_bit(bottom.g(), 4)
+        | channel_bit(bottom.b(), 5)
+}
+
+impl<
+        const LINECTRL_PIN_COUNT: usize,
+        const WIDTH: usize,
+        const HEIGHT: usize,
+        PORT: PortWrite,
+        PIN: OutputPin,
+    > LedMatrix<LINECTRL_PIN_COUNT, WIDTH, HEIGHT, PORT, PIN>
+{
+    /// Applied to the canvas colors when they are drawn, the canvas itself is not modified.
+    /// A [`BitPlaneFrame`] must be converted again after a change.
+    pub fn set_color_correction(&mut self, color_correction: ColorCorrection) {
+        self.color_correction = color_correction;
+    }
+
+    pub fn color_correction(&self) -> &ColorCorrection {
+        &self.color_correction
+    }
+
+    /// By default, [`HalfScan`]. A [`BitPlaneFrame`] must be converted again after a change.
+    pub fn set_scan_mapping(&mut self, scan_mapping: &'static dyn ScanMapping<WIDTH, HEIGHT>) {
+        let scan_lines = scan_mapping.scan_lines();
+        assert!(
+            scan_lines > 0 && scan_lines <= 1 << LINECTRL_PIN_COUNT,
+            "Scan lines cannot be addressed with the line control pins"
+        );
+        assert!(
+            scan_mapping.is_valid(),
+            "Scan mapping does not fit the panel size"
+        );
+        assert_eq!(
+            2 * scan_lines * scan_mapping.chain_length(),
+            WIDTH * HEIGHT,
+            "Scan mapping does not cover the whole panel"
+        );
+        self.scan_mapping = scan_mapping;
+    }
+
+    pub fn scan_mapping(&self) -> &'static dyn ScanMapping<WIDTH, HEIGHT> {
+        self.scan_mapping
+    }
+
+    /// The chip is initialized by [`init_panel_chip`](Self::init_panel_chip).
+    pub fn set_panel_chip(&mut self, panel_chip: PanelChip) {
+        self.panel_chip = panel_chip;
+    }
+
+    pub fn panel_chip(&self) -> PanelChip {
+        self.panel_chip
+    }
+
+    /// Write the configuration registers of the panel chip, if it has to.
+    /// To call before drawing, and after each power loss of the panel.
+    pub fn init_panel_chip(&mut self) -> Result<()> {
+        self.disable_output()?;
+        let columns = self.scan_mapping.chain_length();
+        for register_write in self.panel_chip.init_sequence() {
+            for column in 0..columns {
+                let color_word = if register_write.value & (0x8000 >> (column % 16)) != 0 {
+                    ALL_COLOR_PINS
+                } else {
+                    0
+                };
+                self.port.write_colors(color_word).map_err(Error::pin)?;
+                if column + register_write.register > columns {
+                    self.pin_lat.set_high().map_err(Error::pin)?;
+                }
+                self.clock_color()?;
+            }
+            self.pin_lat.set_low().map_err(Error::pin)?;
+        }
+        self.port.write_colors(0).map_err(Error::pin)
+    }
+
+    /// Number of lines drawn for a whole frame
+    pub fn scan_lines(&self) -> usize {
+        self.scan_mapping.scan_lines()
+    }
+
     pub fn draw_canvas_line(
         &mut self,
         canvas: &Canvas<WIDTH, HEIGHT>,
         line: usize,
         bit_position: ColorBitPosition,
-    ) {
-        self.pin_oe.set_high().unwrap();
-        let half_height = HEIGHT / 2;
+    ) -> Result<()> {
+        self.disable_output()?;
         let raw_canvas = canvas.as_ref();
-        let line_index = line;
-
-        // Initial code using iterator chain below, more compact.
-        // However, it is not performant enough, max BCM was 2, it was flickering a lot when set above
-        //
-        // let color_chain = color_top.into_iter().chain(color_bottom.into_iter());
-        // let pin_chain = self
-        //     .top_colors
-        //     .iter_mut()
-        //     .chain(self.bottom_colors.iter_mut());
-        // for (pin, color) in pin_chain.zip(color_chain) {
-        //     if (color >> bit_position.0) & 0x01 == 1 {
-        //         pin.set_high().unwrap();
-        //     } else {
-        //         pin.set_low().unwrap();
-        //     }
-
-        let bit_mask = 1 << bit_position.0;
-
-        // Internal macro to set pin level
-        macro_rules! set_pin {
-            ($pin:expr, $val:expr) => {
-                if ($val & bit_mask) != 0 {
-                    $pin.set_high().unwrap();
-                } else {
-                    $pin.set_low().unwrap();
-                }
-            };
-        }
-
-        for col_index in 0..WIDTH {
-            let color_top = &raw_canvas[line_index][col_index];
-            let color_bottom = &raw_canvas[line_index + half_height][col_index];
-
-            set_pin!(self.top_colors[0], color_top.r());
-            set_pin!(self.top_colors[1], color_top.g());
-            set_pin!(self.top_colors[2], color_top.b());
 
-            set_pin!(self.bottom_colors[0], color_bottom.r());
-            set_pin!(self.bottom_colors[1], color_bottom.g());
-            set_pin!(self.bottom_colors[2], color_bottom.b());
+        // Initial code used an iterator chain over the pins, then a call to the
+        // HAL for each pin. It was not performant enough, max BCM was 2, then 8,
+        // and it was flickering a lot when set above.
+        // The six color pins are now written at once through the port registers
+        // (see `NrfColorPort`), with the pin masks precomputed for each
+        // combination of color bits.
+        for index in 0..self.scan_mapping.chain_length() {
+            let (top_x, top_y) = self
+                .scan_mapping
+                .pixel_position(line, index, PanelHalf::Top);
+            let (bottom_x, bottom_y) =
+                self.scan_mapping
+                    .pixel_position(line, index, PanelHalf::Bottom);
+            let word = color_word(
+                &self.color_correction.apply(&raw_canvas[top_y][top_x]),
+                &self.color_correction.apply(&raw_canvas[bottom_y][bottom_x]),
+                bit_position,
+            );
+            self.port.write_colors(word as u8).map_err(Error::pin)?;
+            self.clock_color()?;
+        }
+        self.latch_to_line(line)?;
+        self.enable_output()
+    }
 
-            self.clock_color();
+    /// Same as [`draw_canvas_line`](Self::draw_canvas_line), with the color words
+    /// already computed in `frame`.
+    pub fn draw_frame_line(
+        &mut self,
+        frame: &BitPlaneFrame<WIDTH, HEIGHT>,
+        line: usize,
+        bit_position: ColorBitPosition,
+    ) -> Result<()> {
+        self.disable_output()?;
+        for &word in frame.line_words(line, bit_position) {
+            self.port.write_colors(word).map_err(Error::pin)?;
+            self.clock_color()?;
         }
-        self.latch_to_line(line);
-        self.pin_oe.set_low().unwrap();
+        self.latch_to_line(line)?;
+        self.enable_output()
     }
 
-    pub fn draw_canvas(&mut self, canvas: &Canvas<WIDTH, HEIGHT>, bit_position: ColorBitPosition) {
+    /// Display on `line` the color words already shifted out by another shifter,
+    /// such as the [`DmaShifter`](crate::dma::DmaShifter) prototype.
+    pub fn display_shifted_line(&mut self, line: usize) -> Result<()> {
+        self.disable_output()?;
+        self.latch_to_line(line)?;
+        self.enable_output()
+    }
+
+    #[cfg(feature = "nrf52833")]
+    pub fn draw_canvas(
+        &mut self,
+        canvas: &Canvas<WIDTH, HEIGHT>,
+        bit_position: ColorBitPosition,
+    ) -> Result<()> {
         // Here, the usage of the TIMER4 is completely fake, it is just to have the right type when using None
         // Is it possible to have something less far-fetched?
         // Implmenting a dummy struct to reference its type seems not possible since microbit::hal::timer::Instance is a sealed trait.
@@ -188,22 +429,22 @@ impl<const LINECTRL_PIN_COUNT: usize, const WIDTH: usize, const HEIGHT: usize>
             canvas,
             None::<&mut Timer16Mhz<MatrixTimer>>,
             bit_position,
-        );
+        )
     }
 
+    #[cfg(feature = "nrf52833")]
     pub fn draw_canvas_with_delay_buffer<T: nrf52833_hal::timer::Instance>(
         &mut self,
         canvas: &Canvas<WIDTH, HEIGHT>,
         mut timer: Option<&mut Timer16Mhz<T>>,
         bit_position: ColorBitPosition,
-    ) {
-        let half_height = HEIGHT / 2;
+    ) -> Result<()> {
         let mut line_time_avg = 0_f32;
-        for line_index in 0..half_height {
+        for line_index in 0..self.scan_lines() {
             if let Some(unwrapped_timer) = &mut timer {
                 unwrapped_timer.start(u32::MAX);
             }
-            self.draw_canvas_line(canvas, line_index, bit_position);
+            self.draw_canvas_line(canvas, line_index, bit_position)?;
             if let Some(unwrapped_timer) = &mut timer {
                 let counter_delta = unwrapped_timer.read();
                 line_time_avg = line_time_avg * (line_index as f32 / (line_index + 1) as f32)
@@ -212,28 +453,299 @@ impl<const LINECTRL_PIN_COUNT: usize, const WIDTH: usize, const HEIGHT: usize>
         }
         // Wait one line cycle, and simulate a end of latch_to_line
         if let Some(unwrapped_timer) = &mut timer {
-            unwrapped_timer.delay_us(line_time_avg as u32);
-            self.pin_oe.set_high().unwrap();
+            unwrapped_timer.delay_us(line_time_avg as u32 / Timer16Mhz::<T>::TICKS_PER_MICROSEC);
+            self.disable_output()?;
         }
 
         // TODO: should print that only every n times, and should avoid the cfg marco
         //log!("Mean value {}", line_time_avg as u32);
+        Ok(())
+    }
+
+    /// Switch off the LEDs (OE high), until the next drawn line
+    pub fn disable_output(&mut self) -> Result<()> {
+        self.pin_oe.set_high().map_err(Error::pin)
+    }
+
+    /// Keep the LEDs off while the lines are still drawn and latched, so the
+    /// panel keeps being scanned.
+    pub fn set_blanked(&mut self, blanked: bool) -> Result<()> {
+        self.blanked = blanked;
+        if blanked {
+            self.disable_output()?;
+        }
+        Ok(())
+    }
+
+    pub fn is_blanked(&self) -> bool {
+        self.blanked
+    }
+
+    fn enable_output(&mut self) -> Result<()> {
+        if !self.blanked {
+            self.pin_oe.set_low().map_err(Error::pin)?;
+        }
+        Ok(())
     }
 
-    fn latch_to_line(&mut self, line: usize) {
+    fn latch_to_line(&mut self, line: usize) -> Result<()> {
         let mline = line % 2_usize.pow(LINECTRL_PIN_COUNT as u32);
         for pin_idx in 0..self.line_ctrl.len() {
             let enable_pin = (mline & (1 << pin_idx)) != 0;
             self.line_ctrl[pin_idx]
                 .set_state(PinState::from(enable_pin))
+                .map_err(Error::pin)?;
+        }
+        self.pin_lat.set_high().map_err(Error::pin)?;
+        self.pin_lat.set_low().map_err(Error::pin)
+    }
+
+    #[inline(always)]
+    fn clock_color(&mut self) -> Result<()> {
+        self.port.pulse_clock().map_err(Error::pin)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scan::StripedScan;
+    use crate::test_util::gradient_canvas;
+    use embedded_hal::digital::{ErrorKind, ErrorType};
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    use std::vec::Vec;
+
+    #[derive(Copy, Clone, Debug, PartialEq)]
+    enum PinName {
+        Line(usize),
+        Lat,
+        Oe,
+    }
+
+    #[derive(Copy, Clone, Debug, PartialEq)]
+    enum Event {
+        Colors(u8),
+        Clock,
+        Pin(PinName, bool),
+    }
+
+    type Log = Rc<RefCell<Vec<Event>>>;
+
+    struct MockPort(Log);
+
+    impl PortWrite for MockPort {
+        type Error = ErrorKind;
+
+        fn write_colors(&mut self, color_word: u8) -> core::result::Result<(), ErrorKind> {
+            self.0.borrow_mut().push(Event::Colors(color_word));
+            Ok(())
+        }
+
+        fn pulse_clock(&mut self) -> core::result::Result<(), ErrorKind> {
+            self.0.borrow_mut().push(Event::Clock);
+            Ok(())
+        }
+    }
+
+    struct MockPin {
+        name: PinName,
+        log: Log,
+        failing: bool,
+    }
+
+    impl ErrorType for MockPin {
+        type Error = ErrorKind;
+    }
+
+    impl OutputPin for MockPin {
+        fn set_low(&mut self) -> core::result::Result<(), ErrorKind> {
+            self.set_state(PinState::Low)
+        }
+
+        fn set_high(&mut self) -> core::result::Result<(), ErrorKind> {
+            self.set_state(PinState::High)
+        }
+
+        fn set_state(&mut self, state: PinState) -> core::result::Result<(), ErrorKind> {
+            if self.failing {
+                return Err(ErrorKind::Other);
+            }
+            let level = state == PinState::High;
+            self.log.borrow_mut().push(Event::Pin(self.name, level));
+            Ok(())
+        }
+    }
+
+    /// 4x8 panel with HalfScan: 4 scan lines of 4 pixels
+    type TestMatrix = LedMatrix<2, 4, 8, MockPort, MockPin>;
+
+    fn test_matrix(failing: Option<PinName>) -> (TestMatrix, Log) {
+        let log = Log::default();
+        let pin = |name| MockPin {
+            name,
+            log: log.clone(),
+            failing: failing == Some(name),
+        };
+        let led_matrix = LedMatrix::from_parts(
+            MockPort(log.clone()),
+            [pin(PinName::Line(0)), pin(PinName::Line(1))],
+            pin(PinName::Lat),
+            pin(PinName::Oe),
+        );
+        (led_matrix, log)
+    }
+
+    /// Events expected when drawing the line 3 of the gradient canvas at `bit_position`
+    fn expected_line_3(bit_position: ColorBitPosition, enabled: bool) -> Vec<Event> {
+        let canvas = gradient_canvas::<4, 8>();
+        let mut expected = std::vec![Event::Pin(PinName::Oe, true)];
+        for x in 0..4 {
+            let word = color_word(&canvas.0[3][x], &canvas.0[7][x], bit_position);
+            expected.extend([Event::Colors(word as u8), Event::Clock]);
+        }
+        expected.extend([
+            Event::Pin(PinName::Line(0), true),
+            Event::Pin(PinName::Line(1), true),
+            Event::Pin(PinName::Lat, true),
+            Event::Pin(PinName::Lat, false),
+        ]);
+        if enabled {
+            expected.push(Event::Pin(PinName::Oe, false));
+        }
+        expected
+    }
+
+    #[test]
+    fn color_word_selects_the_bit_of_each_channel() {
+        let top = Color::new(0x80, 0x00, 0x81);
+        let bottom = Color::new(0x00, 0x80, 0x01);
+        assert_eq!(color_word(&top, &bottom, ColorBitPosition(7)), 0b01_0101);
+        assert_eq!(color_word(&top, &bottom, ColorBitPosition(0)), 0b10_0100);
+        assert_eq!(color_word(&top, &bottom, ColorBitPosition(3)), 0);
+        assert_eq!(
+            color_word(&Color::WHITE, &Color::WHITE, ColorBitPosition(5)),
+            COLOR_WORD_COUNT - 1
+        );
+    }
+
+    #[test]
+    fn draw_canvas_line_sequence() {
+        for bit in [0, 1, 7] {
+            let (mut led_matrix, log) = test_matrix(None);
+            led_matrix
+                .draw_canvas_line(&gradient_canvas::<4, 8>(), 3, ColorBitPosition(bit))
                 .unwrap();
+            assert_eq!(*log.borrow(), expected_line_3(ColorBitPosition(bit), true));
         }
-        self.pin_lat.set_high().unwrap();
-        self.pin_lat.set_low().unwrap();
     }
 
-    fn clock_color(&mut self) {
-        self.pin_clk.set_high().unwrap();
-        self.pin_clk.set_low().unwrap();
+    #[test]
+    fn draw_frame_line_matches_draw_canvas_line() {
+        let frame = BitPlaneFrame::from_canvas(
+            &gradient_canvas::<4, 8>(),
+            &ColorCorrection::IDENTITY,
+            &HalfScan,
+        );
+        for bit in 0..=ColorBitPosition::MSB_POSITION {
+            let (mut led_matrix, log) = test_matrix(None);
+            led_matrix
+                .draw_frame_line(&frame, 3, ColorBitPosition(bit))
+                .unwrap();
+            assert_eq!(*log.borrow(), expected_line_3(ColorBitPosition(bit), true));
+        }
+    }
+
+    #[test]
+    fn shifted_line_is_only_latched() {
+        let (mut led_matrix, log) = test_matrix(None);
+        led_matrix.display_shifted_line(3).unwrap();
+        let expected: Vec<Event> = expected_line_3(ColorBitPosition(7), true)
+            .into_iter()
+            .filter(|event| matches!(event, Event::Pin(..)))
+            .collect();
+        assert_eq!(*log.borrow(), expected);
+    }
+
+    #[test]
+    fn blanked_matrix_keeps_output_disabled() {
+        let (mut led_matrix, log) = test_matrix(None);
+        led_matrix.set_blanked(true).unwrap();
+        assert_eq!(
+            log.borrow_mut().drain(..).collect::<Vec<_>>(),
+            [Event::Pin(PinName::Oe, true)]
+        );
+        led_matrix
+            .draw_canvas_line(&gradient_canvas::<4, 8>(), 3, ColorBitPosition(7))
+            .unwrap();
+        assert_eq!(*log.borrow(), expected_line_3(ColorBitPosition(7), false));
+    }
+
+    #[test]
+    fn failing_pin_is_reported() {
+        for name in [PinName::Line(1), PinName::Lat, PinName::Oe] {
+            let (mut led_matrix, _) = test_matrix(Some(name));
+            let result =
+                led_matrix.draw_canvas_line(&gradient_canvas::<4, 8>(), 3, ColorBitPosition(7));
+            assert_eq!(result, Err(Error::Pin), "{name:?}");
+        }
+    }
+
+    #[test]
+    fn generic_chip_needs_no_initialization() {
+        let (mut led_matrix, log) = test_matrix(None);
+        led_matrix.init_panel_chip().unwrap();
+        assert_eq!(
+            *log.borrow(),
+            [Event::Pin(PinName::Oe, true), Event::Colors(0)]
+        );
+    }
+
+    #[test]
+    fn fm6126a_registers_are_shifted_msb_first() {
+        let (mut led_matrix, log) = test_matrix(None);
+        led_matrix.set_panel_chip(PanelChip::Fm6126a);
+        led_matrix.init_panel_chip().unwrap();
+        let colors: Vec<u8> = log
+            .borrow()
+            .iter()
+            .filter_map(|event| match event {
+                Event::Colors(word) => Some(*word),
+                _ => None,
+            })
+            .collect();
+        // The four first bits of 0x7FFF then of 0x0040, then the colors back to 0
+        let register_12 = [0, ALL_COLOR_PINS, ALL_COLOR_PINS, ALL_COLOR_PINS];
+        let register_13 = [0, 0, 0, 0];
+        assert_eq!(colors, [&register_12[..], &register_13[..], &[0]].concat());
+        assert_eq!(log.borrow().last(), Some(&Event::Colors(0)));
+        let lat_low = Event::Pin(PinName::Lat, false);
+        assert_eq!(log.borrow().iter().filter(|&&e| e == lat_low).count(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Scan mapping does not fit the panel size")]
+    fn striped_scan_must_divide_the_panel() {
+        static MAPPING: StripedScan = StripedScan {
+            scan_lines: 2,
+            stripe_width: 3,
+            bottom_row_first: false,
+            zigzag: false,
+        };
+        let (mut led_matrix, _) = test_matrix(None);
+        led_matrix.set_scan_mapping(&MAPPING);
+    }
+
+    #[test]
+    #[should_panic(expected = "Scan lines cannot be addressed")]
+    fn scan_mapping_must_be_addressable() {
+        static MAPPING: StripedScan = StripedScan {
+            scan_lines: 8,
+            stripe_width: 4,
+            bottom_row_first: false,
+            zigzag: false,
+        };
+        let (mut led_matrix, _) = test_matrix(None);
+        led_matrix.set_scan_mapping(&MAPPING);
     }
 }
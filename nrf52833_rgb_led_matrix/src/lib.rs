@@ -22,30 +22,80 @@ OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 SOFTWARE.
 */
 
-#![no_std]
+#![cfg_attr(not(test), no_std)]
 
+#[cfg(feature = "std")]
+extern crate std;
+
+pub mod bitplane;
 pub mod canvas;
+pub mod chain;
+pub mod chip;
+#[cfg(feature = "dma-prototype")]
+pub mod dma;
+#[cfg(feature = "embassy")]
+pub mod embassy;
+pub mod error;
+#[cfg(any(feature = "nrf52833", feature = "embassy"))]
+pub mod exchange;
 pub mod fonts;
+pub mod gamma;
+#[cfg(feature = "nrf52833")]
 pub mod helpers;
 pub mod ledmatrix;
 pub mod metrics;
 pub mod models;
+pub mod port;
 pub mod readonly_cell;
+#[cfg(feature = "std")]
+pub mod recorder;
+pub mod scan;
+#[cfg(feature = "nrf52833")]
 pub mod scheduler;
+pub mod shapes;
+#[cfg(any(test, feature = "std"))]
+#[doc(hidden)]
+pub mod test_util;
+#[cfg(feature = "nrf52833")]
 pub mod timer;
 
+/// Size of the panel driven by the built-in scheduled matrix and the helpers:
+/// 64x32 by default, 64x64 with the `panel-64x64` feature.
+#[cfg(not(feature = "panel-64x64"))]
+pub mod panel {
+    pub const LINECTRL_PIN_COUNT: usize = 4;
+    pub const WIDTH: usize = 64;
+    pub const HEIGHT: usize = 32;
+}
+
+#[cfg(feature = "panel-64x64")]
+pub mod panel {
+    pub const LINECTRL_PIN_COUNT: usize = 5;
+    pub const WIDTH: usize = 64;
+    pub const HEIGHT: usize = 64;
+}
+
+/// HAL used by the exported macros, so that applications do not need to depend on it.
+#[cfg(feature = "nrf52833")]
+#[doc(hidden)]
+pub use nrf52833_hal as __hal;
+
+#[cfg(feature = "nrf52833")]
 pub type MetricsRtc = nrf52833_hal::pac::RTC2;
+#[cfg(feature = "nrf52833")]
 pub type MatrixTimer = nrf52833_hal::pac::TIMER4;
+#[cfg(feature = "nrf52833")]
 pub const MATRIX_TIMER_INTERRUPT: nrf52833_hal::pac::Interrupt =
     nrf52833_hal::pac::interrupt::TIMER4;
 
+#[cfg(feature = "nrf52833")]
 #[macro_export]
 macro_rules! enable_interrupts {
     (  $( $interrupt_nb:path ), * ) => {
         #[allow(unsafe_code)]
         unsafe {
         $(
-            nrf52833_hal::pac::NVIC::unmask($interrupt_nb);
+            $crate::__hal::pac::NVIC::unmask($interrupt_nb);
         )*
         }
     };
@@ -1,27 +1,38 @@
+#[cfg(feature = "nrf52833")]
 use core::cell::RefCell;
 
+#[cfg(feature = "nrf52833")]
 use cortex_m::interrupt::Mutex;
+#[cfg(feature = "nrf52833")]
 use nrf52833_hal::{
     pac::CLOCK,
     rtc::{Instance, Rtc},
     Clocks,
 };
 
-use crate::{log, readonly_cell::DynamicReadOnlyCell, MetricsRtc};
+use crate::readonly_cell::DynamicReadOnlyCell;
+#[cfg(feature = "nrf52833")]
+use crate::{log, MetricsRtc};
 
+#[cfg(feature = "nrf52833")]
 const DRAW_CYCLE_LOG_PERIOD_MS: u32 = 1_000;
 
+#[cfg(feature = "nrf52833")]
 static TIMER_SOURCE: DynamicReadOnlyCell<RTCTimerSource<MetricsRtc>> = DynamicReadOnlyCell::new();
 
+#[cfg(feature = "nrf52833")]
 type DrawCycleMetric<'a> =
     Mutex<RefCell<Option<AverageFrequencyMeasure<'a, RTCTimerSource<MetricsRtc>, fn(u32)>>>>;
+#[cfg(feature = "nrf52833")]
 pub(crate) static DRAW_CYCLE_METRICS: DrawCycleMetric = Mutex::new(RefCell::new(None));
 
+#[cfg(feature = "nrf52833")]
 #[allow(unused_variables)]
 fn log_image_freq(freq: u32) {
     log!("Image drawing frequency: {} Hz", freq);
 }
 
+#[cfg(feature = "nrf52833")]
 pub fn init_global_time_source(
     clock: CLOCK,
     rtc_periph: MetricsRtc,
@@ -37,6 +48,7 @@ pub fn init_global_time_source(
     &TIMER_SOURCE
 }
 
+#[cfg(feature = "nrf52833")]
 pub fn init_debug_metrics(timer_source: &'static DynamicReadOnlyCell<RTCTimerSource<MetricsRtc>>) {
     cortex_m::interrupt::free(|cs| {
         let mut draw_cycle_measure_borrow = DRAW_CYCLE_METRICS.borrow(cs).borrow_mut();
@@ -54,11 +66,13 @@ pub trait TimerSource {
     fn frequency(&self) -> u32;
 }
 
+#[cfg(feature = "nrf52833")]
 pub struct RTCTimerSource<I> {
     source: Rtc<I>,
 }
 
 /// Do not forget that the low frequency clock must be started before somewhere in the code.
+#[cfg(feature = "nrf52833")]
 impl<I: Instance> RTCTimerSource<I> {
     pub fn new(source: Rtc<I>) -> Self {
         source.enable_counter();
@@ -66,6 +80,7 @@ impl<I: Instance> RTCTimerSource<I> {
     }
 }
 
+#[cfg(feature = "nrf52833")]
 impl<I: Instance> TimerSource for RTCTimerSource<I> {
     fn current_value(&self) -> u32 {
         self.source.get_counter()
@@ -122,3 +137,59 @@ impl<'a, T: TimerSource, F: Fn(u32)> AverageFrequencyMeasure<'a, T, F> {
         self.counter_val = 0;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cell::Cell;
+    use core::sync::atomic::{AtomicU32, Ordering};
+
+    /// Timer of 1 kHz, moved by hand
+    struct FakeTimerSource(AtomicU32);
+
+    impl FakeTimerSource {
+        fn advance(&self, ticks: u32) {
+            let value = self.0.load(Ordering::Relaxed);
+            self.0.store(value.wrapping_add(ticks), Ordering::Relaxed);
+        }
+    }
+
+    impl TimerSource for FakeTimerSource {
+        fn current_value(&self) -> u32 {
+            self.0.load(Ordering::Relaxed)
+        }
+
+        fn frequency(&self) -> u32 {
+            1_000
+        }
+    }
+
+    fn measure_with_period(start: u32, period_ticks: u32, periods: u32) -> Option<u32> {
+        let timer_source = DynamicReadOnlyCell::from(FakeTimerSource(AtomicU32::new(start)));
+        let measured = Cell::new(None);
+        let mut measure =
+            AverageFrequencyMeasure::new(&timer_source, 100, |freq| measured.set(Some(freq)));
+        for _ in 0..periods {
+            measure.inc_period();
+            timer_source.get_ref().advance(period_ticks);
+        }
+        measured.get()
+    }
+
+    #[test]
+    fn no_measure_before_log_period() {
+        assert_eq!(measure_with_period(0, 10, 10), None);
+    }
+
+    #[test]
+    fn average_frequency() {
+        // Every 10 ms, 11 periods counted when 110 ms are elapsed
+        assert_eq!(measure_with_period(0, 10, 12), Some(100));
+        assert_eq!(measure_with_period(0, 4, 30), Some(250));
+    }
+
+    #[test]
+    fn timer_overflow() {
+        assert_eq!(measure_with_period(u32::MAX - 50, 10, 12), Some(100));
+    }
+}
@@ -0,0 +1,71 @@
+/*
+MIT License
+
+Copyright (c) 2026 Vincent Hiribarren
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Output of the color and clock pins, which are changed for each column: a
+//! [`PortWrite`] sets the six colors at once. With the nRF52833, [`NrfColorPort`]
+//! writes the GPIO OUTSET/OUTCLR registers directly, changing the level of several
+//! pins with a single write per port, instead of one HAL call per pin.
+//! [`OutputPinPort`] works with the embedded-hal pins of any MCU, one call per pin.
+
+use embedded_hal::digital::{OutputPin, PinState};
+
+use crate::ledmatrix::COLOR_PIN_COUNT;
+
+#[cfg(feature = "nrf52833")]
+mod nrf;
+#[cfg(feature = "nrf52833")]
+pub use nrf::{NrfColorPort, NrfOutputPin, PortMask, PORT_COUNT};
+
+/// Output of the color and clock pins of a [`LedMatrix`](crate::ledmatrix::LedMatrix).
+pub trait PortWrite {
+    type Error;
+
+    /// Set the six color pins from `color_word`: bit 0 is R1, bit 1 is G1, ..., bit 5 is B2.
+    fn write_colors(&mut self, color_word: u8) -> Result<(), Self::Error>;
+    /// Shift the colors into the panel, the clock going high then low.
+    fn pulse_clock(&mut self) -> Result<(), Self::Error>;
+}
+
+/// Color and clock pins of any MCU, through the embedded-hal [`OutputPin`] trait.
+pub struct OutputPinPort<P> {
+    /// R1, G1, B1, R2, G2, B2
+    pub colors: [P; COLOR_PIN_COUNT],
+    pub clk: P,
+}
+
+impl<P: OutputPin> PortWrite for OutputPinPort<P> {
+    type Error = P::Error;
+
+    fn write_colors(&mut self, color_word: u8) -> Result<(), Self::Error> {
+        for (pin_idx, pin) in self.colors.iter_mut().enumerate() {
+            pin.set_state(PinState::from(color_word & (1 << pin_idx) != 0))?;
+        }
+        Ok(())
+    }
+
+    fn pulse_clock(&mut self) -> Result<(), Self::Error> {
+        self.clk.set_high()?;
+        self.clk.set_low()
+    }
+}
@@ -0,0 +1,188 @@
+/*
+MIT License
+
+Copyright (c) 2026 Vincent Hiribarren
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Ports and pins of the nRF52833.
+
+use core::convert::Infallible;
+
+use embedded_hal::digital::{ErrorType, OutputPin};
+use nrf52833_hal::gpio::{Level, Output, Pin, Port, PushPull};
+use nrf52833_hal::pac::{P0, P1};
+
+use super::PortWrite;
+use crate::ledmatrix::{COLOR_PIN_COUNT, COLOR_WORD_COUNT};
+
+pub const PORT_COUNT: usize = 2;
+
+/// Set of pins, as one bit mask for each GPIO port (P0 and P1).
+#[derive(Copy, Clone, Default, PartialEq)]
+pub struct PortMask(pub [u32; PORT_COUNT]);
+
+impl PortMask {
+    pub const EMPTY: PortMask = PortMask([0; PORT_COUNT]);
+
+    pub fn from_pin<MODE>(pin: &Pin<MODE>) -> Self {
+        let mut mask = Self::EMPTY;
+        let port_index = match pin.port() {
+            Port::Port0 => 0,
+            Port::Port1 => 1,
+        };
+        mask.0[port_index] = 1 << pin.pin();
+        mask
+    }
+
+    pub fn from_pins<MODE>(pins: &[Pin<MODE>]) -> Self {
+        pins.iter()
+            .fold(Self::EMPTY, |mask, pin| mask.union(Self::from_pin(pin)))
+    }
+
+    pub const fn union(self, other: PortMask) -> Self {
+        PortMask([self.0[0] | other.0[0], self.0[1] | other.0[1]])
+    }
+
+    pub const fn difference(self, other: PortMask) -> Self {
+        PortMask([self.0[0] & !other.0[0], self.0[1] & !other.0[1]])
+    }
+
+    /// The pins must already be configured as outputs, and be owned by the caller.
+    #[inline(always)]
+    pub fn set_high(&self) {
+        // Writing 0 to a bit of OUTSET/OUTCLR has no effect, only the pins of the mask are changed.
+        if self.0[0] != 0 {
+            unsafe { (*P0::ptr()).outset.write(|w| w.bits(self.0[0])) };
+        }
+        if self.0[1] != 0 {
+            unsafe { (*P1::ptr()).outset.write(|w| w.bits(self.0[1])) };
+        }
+    }
+
+    /// The pins must already be configured as outputs, and be owned by the caller.
+    #[inline(always)]
+    pub fn set_low(&self) {
+        if self.0[0] != 0 {
+            unsafe { (*P0::ptr()).outclr.write(|w| w.bits(self.0[0])) };
+        }
+        if self.0[1] != 0 {
+            unsafe { (*P1::ptr()).outclr.write(|w| w.bits(self.0[1])) };
+        }
+    }
+
+    /// Set the pins of `high_pins` to high, and the other pins of `self` to low.
+    #[inline(always)]
+    pub fn write(&self, high_pins: PortMask) {
+        self.difference(high_pins).set_low();
+        high_pins.set_high();
+    }
+}
+
+/// Color and clock pins of the nRF52833, with the pins of each color word
+/// precomputed, so the six colors are set with at most one write per register.
+pub struct NrfColorPort {
+    // Only driven through the port registers, kept to retain the ownership of the pins
+    colors: [Pin<Output<PushPull>>; COLOR_PIN_COUNT],
+    clk: Pin<Output<PushPull>>,
+    colors_mask: PortMask,
+    clk_mask: PortMask,
+    /// Pins to set to high, for each color word
+    color_word_masks: [PortMask; COLOR_WORD_COUNT],
+}
+
+impl NrfColorPort {
+    /// The pins are set as outputs, with a low level.
+    pub fn new<MODE>(colors: [Pin<MODE>; COLOR_PIN_COUNT], clk: Pin<MODE>) -> Self {
+        let colors = colors.map(|pin| pin.into_push_pull_output(Level::Low));
+        let clk = clk.into_push_pull_output(Level::Low);
+        let mut color_word_masks = [PortMask::EMPTY; COLOR_WORD_COUNT];
+        for (color_word, mask) in color_word_masks.iter_mut().enumerate() {
+            for (pin_idx, pin) in colors.iter().enumerate() {
+                if color_word & (1 << pin_idx) != 0 {
+                    *mask = mask.union(PortMask::from_pin(pin));
+                }
+            }
+        }
+        NrfColorPort {
+            colors_mask: PortMask::from_pins(&colors),
+            clk_mask: PortMask::from_pin(&clk),
+            color_word_masks,
+            colors,
+            clk,
+        }
+    }
+
+    /// Color pins, from R1 to B2, and clock pin, for a peripheral driving them
+    /// instead of the port registers.
+    pub fn pins(&self) -> (&[Pin<Output<PushPull>>; COLOR_PIN_COUNT], &Pin<Output<PushPull>>) {
+        (&self.colors, &self.clk)
+    }
+
+    /// Give back the color pins, and the clock pin.
+    pub fn release(
+        self,
+    ) -> (
+        [Pin<Output<PushPull>>; COLOR_PIN_COUNT],
+        Pin<Output<PushPull>>,
+    ) {
+        (self.colors, self.clk)
+    }
+}
+
+impl PortWrite for NrfColorPort {
+    type Error = Infallible;
+
+    #[inline(always)]
+    fn write_colors(&mut self, color_word: u8) -> Result<(), Self::Error> {
+        self.colors_mask
+            .write(self.color_word_masks[color_word as usize]);
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn pulse_clock(&mut self) -> Result<(), Self::Error> {
+        self.clk_mask.set_high();
+        self.clk_mask.set_low();
+        Ok(())
+    }
+}
+
+/// embedded-hal 1.0 [`OutputPin`] over a nRF pin, which implements the 0.2 version.
+pub struct NrfOutputPin(pub Pin<Output<PushPull>>);
+
+impl ErrorType for NrfOutputPin {
+    type Error = Infallible;
+}
+
+impl OutputPin for NrfOutputPin {
+    #[inline(always)]
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        // The error of the nRF pins is uninhabited
+        let Ok(()) = embedded_hal_02::digital::v2::OutputPin::set_low(&mut self.0);
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        let Ok(()) = embedded_hal_02::digital::v2::OutputPin::set_high(&mut self.0);
+        Ok(())
+    }
+}
@@ -10,7 +10,7 @@ use core::sync::atomic::{AtomicBool, Ordering};
 /// This can be used in interrupts as well as it implements Sync.
 ///
 /// Usage:
-/// ```rust
+/// ```ignore
 /// static MY_VAR: DynamicReadOnlyCell<u32> = DynamicReadOnlyCell::new();
 ///
 /// fn main() {
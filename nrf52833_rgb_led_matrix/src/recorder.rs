@@ -0,0 +1,681 @@
+/*
+MIT License
+
+Copyright (c) 2026 Vincent Hiribarren
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Recording of the signals sent to the panel, to debug the drawing on the
+//! computer, with the `std` feature.
+//!
+//! A [`Recorder`] gives the color port and the pins of a [`LedMatrix`], which
+//! record each transition of R1..B2, CLK, LAT, OE and of the address lines.
+//! Each port write or pin change lasts [`Recorder::step_ns`]; the display time
+//! of the bit planes is added with [`Recorder::advance_ns`]. The recording is
+//! exported as a VCD file, for GTKWave, or decoded back into what the panel
+//! shows:
+//!
+//! ```
+//! # use nrf52833_rgb_led_matrix::canvas::{Canvas, Color};
+//! # use nrf52833_rgb_led_matrix::ledmatrix::ColorBitPosition;
+//! # use nrf52833_rgb_led_matrix::recorder::Recorder;
+//! # use nrf52833_rgb_led_matrix::scan::HalfScan;
+//! let mut canvas = Canvas::<16, 8>::new();
+//! canvas.draw_pixel(3, 5, Color::new(200, 100, 50));
+//! let recorder = Recorder::new();
+//! let mut led_matrix = recorder.led_matrix::<2, 16, 8>();
+//! for line in 0..led_matrix.scan_lines() {
+//!     for bit in 0..8 {
+//!         led_matrix.draw_canvas_line(&canvas, line, ColorBitPosition(bit)).unwrap();
+//!         recorder.advance_ns(1_000 << bit);
+//!     }
+//! }
+//! led_matrix.disable_output().unwrap();
+//! assert!(recorder.decode_frame(&HalfScan).0 == canvas.0);
+//! recorder.write_vcd(std::io::sink()).unwrap();
+//! ```
+
+use core::cell::RefCell;
+use core::convert::Infallible;
+use std::collections::VecDeque;
+use std::io;
+use std::rc::Rc;
+use std::vec::Vec;
+
+use embedded_hal::digital::{ErrorType, OutputPin};
+
+use crate::canvas::{Canvas, Color};
+use crate::ledmatrix::{LedMatrix, COLOR_PIN_COUNT};
+use crate::port::PortWrite;
+use crate::scan::{PanelHalf, ScanMapping};
+
+/// Duration of a port write or of a pin change, unless changed
+pub const DEFAULT_STEP_NS: u64 = 50;
+
+const COLOR_NAMES: [&str; COLOR_PIN_COUNT] = ["R1", "G1", "B1", "R2", "G2", "B2"];
+const ADDRESS_NAMES: [&str; 5] = ["A", "B", "C", "D", "E"];
+
+/// Input of the panel
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Signal {
+    /// R1, G1, B1, R2, G2, B2, in the order of the color word bits
+    Color(usize),
+    Clk,
+    Lat,
+    Oe,
+    /// Address line, 0 being A
+    Address(usize),
+}
+
+impl Signal {
+    fn index(self) -> usize {
+        match self {
+            Signal::Color(pin_idx) => pin_idx,
+            Signal::Clk => COLOR_PIN_COUNT,
+            Signal::Lat => COLOR_PIN_COUNT + 1,
+            Signal::Oe => COLOR_PIN_COUNT + 2,
+            Signal::Address(bit) => COLOR_PIN_COUNT + 3 + bit,
+        }
+    }
+}
+
+/// Change of level of a signal
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Transition {
+    pub time_ns: u64,
+    pub signal: Signal,
+    pub high: bool,
+}
+
+/// Color words shifted into the panel, latched for the line `address`
+#[derive(Clone, Debug, PartialEq)]
+pub struct LatchedLine {
+    pub time_ns: u64,
+    pub address: usize,
+    /// In the order they were shifted in, as given by [`ScanMapping::pixel_position`]
+    pub words: Vec<u8>,
+}
+
+struct Recording {
+    step_ns: u64,
+    time_ns: u64,
+    address_pin_count: usize,
+    /// Levels of the signals when the recording started
+    start_levels: Levels,
+    transitions: Vec<Transition>,
+}
+
+impl Recording {
+    fn set(&mut self, signal: Signal, high: bool) {
+        self.transitions.push(Transition {
+            time_ns: self.time_ns,
+            signal,
+            high,
+        });
+    }
+
+    fn step(&mut self) {
+        self.time_ns += self.step_ns;
+    }
+}
+
+/// Levels of the signals while the recording is replayed, the address lines
+/// missing from it being low
+#[derive(Clone)]
+struct Levels(Vec<bool>);
+
+impl Levels {
+    /// All the signals low but OE, which keeps the LEDs off until the first drawn line
+    fn initial() -> Self {
+        let mut levels = Levels(std::vec![false; Signal::Address(0).index()]);
+        levels.0[Signal::Oe.index()] = true;
+        levels
+    }
+
+    fn get(&self, signal: Signal) -> bool {
+        self.0.get(signal.index()).copied().unwrap_or(false)
+    }
+
+    /// Apply `transition`, return true if the level changed
+    fn apply(&mut self, transition: &Transition) -> bool {
+        let index = transition.signal.index();
+        if index >= self.0.len() {
+            self.0.resize(index + 1, false);
+        }
+        let level = &mut self.0[index];
+        let changed = *level != transition.high;
+        *level = transition.high;
+        changed
+    }
+
+    fn color_word(&self) -> u8 {
+        (0..COLOR_PIN_COUNT).fold(0, |word, pin_idx| {
+            word | (self.get(Signal::Color(pin_idx)) as u8) << pin_idx
+        })
+    }
+
+    fn address(&self, address_pin_count: usize) -> usize {
+        (0..address_pin_count).fold(0, |address, bit| {
+            address | (self.get(Signal::Address(bit)) as usize) << bit
+        })
+    }
+}
+
+/// Records the signals of a [`LedMatrix`] built with its port and pins.
+///
+/// The recorder is shared by its port and pins, a clone records the same signals.
+#[derive(Clone)]
+pub struct Recorder(Rc<RefCell<Recording>>);
+
+impl Default for Recorder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Recorder {
+    pub fn new() -> Self {
+        Self::with_step_ns(DEFAULT_STEP_NS)
+    }
+
+    /// Each port write or pin change lasts `step_ns`
+    pub fn with_step_ns(step_ns: u64) -> Self {
+        Recorder(Rc::new(RefCell::new(Recording {
+            step_ns,
+            time_ns: 0,
+            address_pin_count: 0,
+            start_levels: Levels::initial(),
+            transitions: Vec::new(),
+        })))
+    }
+
+    /// Matrix driven by the port and the pins of the recorder, with `LINECTRL_PIN_COUNT`
+    /// address lines.
+    pub fn led_matrix<const LINECTRL_PIN_COUNT: usize, const WIDTH: usize, const HEIGHT: usize>(
+        &self,
+    ) -> LedMatrix<LINECTRL_PIN_COUNT, WIDTH, HEIGHT, RecordingPort, RecordingPin> {
+        LedMatrix::from_parts(
+            self.port(),
+            core::array::from_fn(|bit| self.pin(Signal::Address(bit))),
+            self.pin(Signal::Lat),
+            self.pin(Signal::Oe),
+        )
+    }
+
+    /// Color and clock pins
+    pub fn port(&self) -> RecordingPort {
+        RecordingPort(self.clone())
+    }
+
+    /// LAT, OE or an address line; the levels start low, but OE which starts high.
+    pub fn pin(&self, signal: Signal) -> RecordingPin {
+        assert!(
+            !matches!(signal, Signal::Color(_) | Signal::Clk),
+            "Color and clock pins are driven by the port"
+        );
+        if let Signal::Address(bit) = signal {
+            let mut recording = self.0.borrow_mut();
+            recording.address_pin_count = recording.address_pin_count.max(bit + 1);
+        }
+        RecordingPin {
+            recorder: self.clone(),
+            signal,
+        }
+    }
+
+    pub fn step_ns(&self) -> u64 {
+        self.0.borrow().step_ns
+    }
+
+    /// Let the time pass without any transition, for instance to display a bit plane
+    pub fn advance_ns(&self, duration_ns: u64) {
+        self.0.borrow_mut().time_ns += duration_ns;
+    }
+
+    /// Duration of the recording
+    pub fn now_ns(&self) -> u64 {
+        self.0.borrow().time_ns
+    }
+
+    /// Forget the recorded transitions and restart the time from 0, the signals
+    /// keeping their levels, for instance to decode the next frame alone.
+    pub fn restart(&self) {
+        let recording = &mut *self.0.borrow_mut();
+        for transition in &recording.transitions {
+            recording.start_levels.apply(transition);
+        }
+        recording.transitions.clear();
+        recording.time_ns = 0;
+    }
+
+    /// All the recorded transitions, including the ones which do not change the level
+    pub fn transitions(&self) -> Vec<Transition> {
+        self.0.borrow().transitions.clone()
+    }
+
+    /// Lines latched by the panel, with shift registers of `chain_length` color words
+    pub fn latched_lines(&self, chain_length: usize) -> Vec<LatchedLine> {
+        let recording = self.0.borrow();
+        let mut levels = recording.start_levels.clone();
+        let mut shifted = VecDeque::with_capacity(chain_length);
+        let mut latched_lines = Vec::new();
+        for transition in &recording.transitions {
+            if !levels.apply(transition) || !transition.high {
+                continue;
+            }
+            match transition.signal {
+                Signal::Clk => {
+                    if shifted.len() == chain_length {
+                        shifted.pop_front();
+                    }
+                    shifted.push_back(levels.color_word());
+                }
+                Signal::Lat => latched_lines.push(LatchedLine {
+                    time_ns: transition.time_ns,
+                    address: levels.address(recording.address_pin_count),
+                    words: shifted.iter().copied().collect(),
+                }),
+                _ => {}
+            }
+        }
+        latched_lines
+    }
+
+    /// Colors shown by the panel, averaged over the whole recording.
+    ///
+    /// Each channel of a pixel is the part of the time its line is displayed
+    /// (OE low, with this line latched) during which its bit is set: drawing all
+    /// the bit planes of a canvas, with display times doubling from the least
+    /// significant one, gives back the canvas.
+    pub fn decode_frame<const WIDTH: usize, const HEIGHT: usize>(
+        &self,
+        scan_mapping: &dyn ScanMapping<WIDTH, HEIGHT>,
+    ) -> Canvas<WIDTH, HEIGHT> {
+        let chain_length = scan_mapping.chain_length();
+        let recording = self.0.borrow();
+        let address_pin_count = recording.address_pin_count;
+        let mut levels = recording.start_levels.clone();
+        let mut shifted = VecDeque::with_capacity(chain_length);
+        let mut latched: Vec<u8> = Vec::new();
+        // Time each pixel is displayed, and time each of its channels is on
+        let mut displayed_ns = std::vec![[0_u64; WIDTH]; HEIGHT];
+        let mut lit_ns = std::vec![[[0_u64; 3]; WIDTH]; HEIGHT];
+        let mut last_time_ns = 0;
+
+        let mut integrate = |levels: &Levels, latched: &[u8], until_ns: u64| {
+            if levels.get(Signal::Oe) || until_ns == last_time_ns {
+                last_time_ns = until_ns;
+                return;
+            }
+            let duration_ns = until_ns - last_time_ns;
+            last_time_ns = until_ns;
+            let line = levels.address(address_pin_count);
+            if line >= scan_mapping.scan_lines() {
+                return;
+            }
+            for (index, &word) in latched.iter().enumerate() {
+                for (half, first_pin) in [(PanelHalf::Top, 0), (PanelHalf::Bottom, 3)] {
+                    let (x, y) = scan_mapping.pixel_position(line, index, half);
+                    displayed_ns[y][x] += duration_ns;
+                    for (channel, channel_ns) in lit_ns[y][x].iter_mut().enumerate() {
+                        if word & (1 << (first_pin + channel)) != 0 {
+                            *channel_ns += duration_ns;
+                        }
+                    }
+                }
+            }
+        };
+
+        for transition in &recording.transitions {
+            integrate(&levels, &latched, transition.time_ns);
+            if !levels.apply(transition) || !transition.high {
+                continue;
+            }
+            match transition.signal {
+                Signal::Clk => {
+                    if shifted.len() == chain_length {
+                        shifted.pop_front();
+                    }
+                    shifted.push_back(levels.color_word());
+                }
+                Signal::Lat => latched = shifted.iter().copied().collect(),
+                _ => {}
+            }
+        }
+        integrate(&levels, &latched, recording.time_ns);
+
+        let mut canvas = Canvas::new();
+        for (y, row) in canvas.0.iter_mut().enumerate() {
+            for (x, color) in row.iter_mut().enumerate() {
+                if displayed_ns[y][x] == 0 {
+                    continue;
+                }
+                let [r, g, b] = lit_ns[y][x].map(|channel_ns| {
+                    ((channel_ns * u8::MAX as u64 + displayed_ns[y][x] / 2) / displayed_ns[y][x])
+                        as u8
+                });
+                *color = Color::new(r, g, b);
+            }
+        }
+        canvas
+    }
+
+    /// Write the recording as a Value Change Dump, with a nanosecond timescale
+    pub fn write_vcd(&self, mut out: impl io::Write) -> io::Result<()> {
+        let recording = self.0.borrow();
+        let address_pin_count = recording.address_pin_count;
+        let signals = Self::signals(address_pin_count);
+        writeln!(
+            out,
+            "$version {} {} $end",
+            env!("CARGO_PKG_NAME"),
+            env!("CARGO_PKG_VERSION")
+        )?;
+        writeln!(out, "$timescale 1ns $end")?;
+        writeln!(out, "$scope module hub75 $end")?;
+        for &signal in &signals {
+            writeln!(
+                out,
+                "$var wire 1 {} {} $end",
+                vcd_identifier(signal),
+                signal_name(signal)
+            )?;
+        }
+        writeln!(out, "$upscope $end")?;
+        writeln!(out, "$enddefinitions $end")?;
+
+        let mut levels = recording.start_levels.clone();
+        writeln!(out, "#0")?;
+        writeln!(out, "$dumpvars")?;
+        for &signal in &signals {
+            writeln!(
+                out,
+                "{}{}",
+                levels.get(signal) as u8,
+                vcd_identifier(signal)
+            )?;
+        }
+        writeln!(out, "$end")?;
+        let mut last_time_ns = 0;
+        for transition in &recording.transitions {
+            if !levels.apply(transition) {
+                continue;
+            }
+            if transition.time_ns != last_time_ns {
+                writeln!(out, "#{}", transition.time_ns)?;
+                last_time_ns = transition.time_ns;
+            }
+            writeln!(
+                out,
+                "{}{}",
+                transition.high as u8,
+                vcd_identifier(transition.signal)
+            )?;
+        }
+        if recording.time_ns != last_time_ns {
+            writeln!(out, "#{}", recording.time_ns)?;
+        }
+        Ok(())
+    }
+
+    fn signals(address_pin_count: usize) -> Vec<Signal> {
+        (0..COLOR_PIN_COUNT)
+            .map(Signal::Color)
+            .chain([Signal::Clk, Signal::Lat, Signal::Oe])
+            .chain((0..address_pin_count).map(Signal::Address))
+            .collect()
+    }
+}
+
+fn signal_name(signal: Signal) -> std::string::String {
+    match signal {
+        Signal::Color(pin_idx) => COLOR_NAMES[pin_idx].into(),
+        Signal::Clk => "CLK".into(),
+        Signal::Lat => "LAT".into(),
+        Signal::Oe => "OE".into(),
+        Signal::Address(bit) => match ADDRESS_NAMES.get(bit) {
+            Some(name) => (*name).into(),
+            None => std::format!("A{bit}"),
+        },
+    }
+}
+
+/// Printable ASCII character, from `!`
+fn vcd_identifier(signal: Signal) -> char {
+    char::from(b'!' + signal.index() as u8)
+}
+
+/// Color and clock pins of a [`Recorder`]
+pub struct RecordingPort(Recorder);
+
+impl PortWrite for RecordingPort {
+    type Error = Infallible;
+
+    fn write_colors(&mut self, color_word: u8) -> Result<(), Self::Error> {
+        let mut recording = self.0 .0.borrow_mut();
+        for pin_idx in 0..COLOR_PIN_COUNT {
+            recording.set(Signal::Color(pin_idx), color_word & (1 << pin_idx) != 0);
+        }
+        recording.step();
+        Ok(())
+    }
+
+    fn pulse_clock(&mut self) -> Result<(), Self::Error> {
+        let mut recording = self.0 .0.borrow_mut();
+        recording.set(Signal::Clk, true);
+        recording.step();
+        recording.set(Signal::Clk, false);
+        recording.step();
+        Ok(())
+    }
+}
+
+/// LAT, OE or address pin of a [`Recorder`]
+pub struct RecordingPin {
+    recorder: Recorder,
+    signal: Signal,
+}
+
+impl ErrorType for RecordingPin {
+    type Error = Infallible;
+}
+
+impl OutputPin for RecordingPin {
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        let mut recording = self.recorder.0.borrow_mut();
+        recording.set(self.signal, false);
+        recording.step();
+        Ok(())
+    }
+
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        let mut recording = self.recorder.0.borrow_mut();
+        recording.set(self.signal, true);
+        recording.step();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ledmatrix::{color_word, ColorBitPosition};
+    use crate::scan::{HalfScan, StripedScan};
+    use crate::test_util::gradient_canvas;
+    use std::string::String;
+
+    /// All the bit planes of all the lines, each one displayed twice longer than the previous one
+    fn record_frame(recorder: &Recorder, scan_mapping: &'static dyn ScanMapping<32, 16>) {
+        let canvas = gradient_canvas::<32, 16>();
+        let mut led_matrix = recorder.led_matrix::<3, 32, 16>();
+        led_matrix.set_scan_mapping(scan_mapping);
+        for line in 0..led_matrix.scan_lines() {
+            for bit in 0..=ColorBitPosition::MSB_POSITION {
+                led_matrix
+                    .draw_canvas_line(&canvas, line, ColorBitPosition(bit))
+                    .unwrap();
+                recorder.advance_ns(1_000 << bit);
+            }
+        }
+        led_matrix.disable_output().unwrap();
+    }
+
+    #[test]
+    fn latched_lines_hold_the_color_words() {
+        let canvas = gradient_canvas::<32, 16>();
+        let recorder = Recorder::new();
+        let mut led_matrix = recorder.led_matrix::<3, 32, 16>();
+        led_matrix
+            .draw_canvas_line(&canvas, 5, ColorBitPosition(4))
+            .unwrap();
+        let latched_lines = recorder.latched_lines(32);
+        assert_eq!(latched_lines.len(), 1);
+        assert_eq!(latched_lines[0].address, 5);
+        for (x, &word) in latched_lines[0].words.iter().enumerate() {
+            let expected = color_word(&canvas.0[5][x], &canvas.0[13][x], ColorBitPosition(4));
+            assert_eq!(word as usize, expected);
+        }
+    }
+
+    #[test]
+    fn decoded_frame_is_the_canvas() {
+        static STRIPED: StripedScan = StripedScan {
+            scan_lines: 4,
+            stripe_width: 8,
+            bottom_row_first: true,
+            zigzag: true,
+        };
+        let scan_mappings: [&'static dyn ScanMapping<32, 16>; 2] = [&HalfScan, &STRIPED];
+        for scan_mapping in scan_mappings {
+            let recorder = Recorder::new();
+            record_frame(&recorder, scan_mapping);
+            assert_eq!(
+                recorder.decode_frame(scan_mapping).0,
+                gradient_canvas::<32, 16>().0
+            );
+        }
+    }
+
+    #[test]
+    fn blanked_panel_shows_nothing() {
+        let recorder = Recorder::new();
+        let mut led_matrix = recorder.led_matrix::<3, 32, 16>();
+        led_matrix.set_blanked(true).unwrap();
+        led_matrix
+            .draw_canvas_line(&gradient_canvas::<32, 16>(), 1, ColorBitPosition(7))
+            .unwrap();
+        recorder.advance_ns(1_000);
+        let decoded: Canvas<32, 16> = recorder.decode_frame(&HalfScan);
+        assert!(decoded.0.iter().flatten().all(|&c| c == Color::BLACK));
+    }
+
+    #[test]
+    fn restarted_recording_keeps_the_levels() {
+        let recorder = Recorder::new();
+        let mut led_matrix = recorder.led_matrix::<3, 32, 16>();
+        led_matrix
+            .draw_canvas_line(&Canvas::new(), 2, ColorBitPosition(7))
+            .unwrap();
+        recorder.restart();
+        assert!(recorder.transitions().is_empty());
+        assert_eq!(recorder.now_ns(), 0);
+        // OE was left low
+        let mut vcd = Vec::new();
+        recorder.write_vcd(&mut vcd).unwrap();
+        let vcd = String::from_utf8(vcd).unwrap();
+        let (_, dumpvars) = vcd.split_once("$dumpvars\n").unwrap();
+        let oe = std::format!("0{}", vcd_identifier(Signal::Oe));
+        assert!(dumpvars
+            .lines()
+            .take_while(|&line| line != "$end")
+            .any(|line| line == oe));
+
+        record_frame(&recorder, &HalfScan);
+        recorder.restart();
+        record_frame(&recorder, &HalfScan);
+        assert_eq!(
+            recorder.decode_frame(&HalfScan).0,
+            gradient_canvas::<32, 16>().0
+        );
+    }
+
+    #[test]
+    fn steps_are_timed() {
+        let recorder = Recorder::with_step_ns(10);
+        let mut port = recorder.port();
+        port.write_colors(0b00_0001).unwrap();
+        port.pulse_clock().unwrap();
+        recorder.advance_ns(100);
+        let transitions = recorder.transitions();
+        assert_eq!(transitions.len(), COLOR_PIN_COUNT + 2);
+        assert_eq!(
+            transitions[COLOR_PIN_COUNT..],
+            [
+                Transition {
+                    time_ns: 10,
+                    signal: Signal::Clk,
+                    high: true
+                },
+                Transition {
+                    time_ns: 20,
+                    signal: Signal::Clk,
+                    high: false
+                },
+            ]
+        );
+        assert_eq!(recorder.now_ns(), 130);
+    }
+
+    #[test]
+    fn vcd_dumps_the_level_changes() {
+        let recorder = Recorder::with_step_ns(10);
+        let mut led_matrix = recorder.led_matrix::<2, 4, 8>();
+        led_matrix.disable_output().unwrap();
+        led_matrix.disable_output().unwrap();
+        led_matrix
+            .draw_canvas_line(&Canvas::new(), 1, ColorBitPosition(7))
+            .unwrap();
+        let mut vcd = Vec::new();
+        recorder.write_vcd(&mut vcd).unwrap();
+        let vcd = String::from_utf8(vcd).unwrap();
+
+        for name in ["R1", "B2", "CLK", "LAT", "OE", "A", "B"] {
+            assert!(vcd.contains(&std::format!(" {name} $end")), "{name}");
+        }
+        assert!(!vcd.contains(" C $end"));
+        let (header, changes) = vcd.split_once("$enddefinitions $end\n").unwrap();
+        assert!(header.contains("$timescale 1ns $end"));
+        assert!(changes.starts_with("#0\n$dumpvars\n"));
+        // OE starts high, and only goes low at the end of the line
+        let oe = vcd_identifier(Signal::Oe);
+        assert_eq!(changes.matches(&std::format!("\n0{oe}\n")).count(), 1);
+        assert_eq!(changes.matches(&std::format!("\n1{oe}\n")).count(), 1);
+        // Four clock pulses
+        let clk = vcd_identifier(Signal::Clk);
+        assert_eq!(changes.matches(&std::format!("\n1{clk}\n")).count(), 4);
+        let times: Vec<u64> = changes
+            .lines()
+            .filter_map(|line| line.strip_prefix('#'))
+            .map(|time| time.parse().unwrap())
+            .collect();
+        assert!(times.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(times.last(), Some(&recorder.now_ns()));
+    }
+}
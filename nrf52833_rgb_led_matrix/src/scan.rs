@@ -0,0 +1,232 @@
+/*
+MIT License
+
+Copyright (c) 2026 Vincent Hiribarren
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Order in which the pixels of the canvas are shifted out to the panel.
+//!
+//! A panel has two halves, driven by R1, G1, B1 and by R2, G2, B2, which are
+//! clocked together. On a 1/16-scan 32-high panel, each address selects one
+//! row of each half, and the shift registers of a half span one row. Cheaper
+//! or outdoor panels with a 1/8 or 1/4 scan light several rows of each half
+//! for one address, their shift registers then span those rows, often in
+//! stripes of a few pixels, sometimes in zig-zag. A [`ScanMapping`] gives, for
+//! each address and each shifted pixel, the matching position in the canvas:
+//!
+//! ```ignore
+//! // 32x16 outdoor panel, 1/4 scan, with stripes of 8 pixels
+//! static MAPPING: StripedScan = StripedScan {
+//!     scan_lines: 4,
+//!     stripe_width: 8,
+//!     bottom_row_first: true,
+//!     zigzag: false,
+//! };
+//! led_matrix.set_scan_mapping(&MAPPING);
+//! ```
+
+/// Half of the panel, the top one is driven by R1, G1, B1, the bottom one by R2, G2, B2
+#[derive(Copy, Clone, PartialEq)]
+pub enum PanelHalf {
+    Top,
+    Bottom,
+}
+
+/// `Sync`, as the mapping is shared with the interrupt displaying the frames.
+pub trait ScanMapping<const WIDTH: usize, const HEIGHT: usize>: Sync {
+    /// Number of addresses selected in turn by the line control pins
+    fn scan_lines(&self) -> usize;
+
+    /// Number of pixels shifted out for one address, for each half
+    fn chain_length(&self) -> usize {
+        WIDTH * HEIGHT / (2 * self.scan_lines())
+    }
+
+    /// Canvas position `(x, y)` of the `index`th pixel shifted out for the address `line`.
+    fn pixel_position(&self, line: usize, index: usize, half: PanelHalf) -> (usize, usize);
+
+    /// False if the parameters of the mapping do not fit a `WIDTH` x `HEIGHT`
+    /// canvas, checked when the mapping is set.
+    fn is_valid(&self) -> bool {
+        true
+    }
+}
+
+/// Usual mapping of indoor panels: row `line` and row `line + HEIGHT / 2`
+/// are displayed together, with the pixels shifted from left to right.
+pub struct HalfScan;
+
+impl<const WIDTH: usize, const HEIGHT: usize> ScanMapping<WIDTH, HEIGHT> for HalfScan {
+    fn scan_lines(&self) -> usize {
+        HEIGHT / 2
+    }
+
+    fn chain_length(&self) -> usize {
+        WIDTH
+    }
+
+    #[inline]
+    fn pixel_position(&self, line: usize, index: usize, half: PanelHalf) -> (usize, usize) {
+        match half {
+            PanelHalf::Top => (index, line),
+            PanelHalf::Bottom => (index, line + HEIGHT / 2),
+        }
+    }
+}
+
+/// Mapping of panels whose address lights several rows of each half.
+///
+/// The rows lit by `line` are `line`, `line + scan_lines`, `line + 2 * scan_lines`...
+/// The shift registers alternate between those rows every `stripe_width` pixels,
+/// from the left of the panel to its right.
+#[derive(Copy, Clone)]
+pub struct StripedScan {
+    /// 8 for a 1/8-scan panel, 4 for a 1/4-scan one..., must divide half the height
+    pub scan_lines: usize,
+    /// Number of consecutive pixels of a row in the chain, must divide the width
+    pub stripe_width: usize,
+    /// Stripes start with the lowest of the rows instead of the highest one
+    pub bottom_row_first: bool,
+    /// Every other stripe is shifted from right to left
+    pub zigzag: bool,
+}
+
+impl<const WIDTH: usize, const HEIGHT: usize> ScanMapping<WIDTH, HEIGHT> for StripedScan {
+    fn scan_lines(&self) -> usize {
+        self.scan_lines
+    }
+
+    fn pixel_position(&self, line: usize, index: usize, half: PanelHalf) -> (usize, usize) {
+        let rows_per_line = HEIGHT / 2 / self.scan_lines;
+        let stripe = index / self.stripe_width;
+        let mut column_offset = index % self.stripe_width;
+        if self.zigzag && stripe % 2 == 1 {
+            column_offset = self.stripe_width - 1 - column_offset;
+        }
+        let mut row = stripe % rows_per_line;
+        if self.bottom_row_first {
+            row = rows_per_line - 1 - row;
+        }
+        let x = (stripe / rows_per_line) * self.stripe_width + column_offset;
+        let y = line + row * self.scan_lines;
+        match half {
+            PanelHalf::Top => (x, y),
+            PanelHalf::Bottom => (x, y + HEIGHT / 2),
+        }
+    }
+
+    fn is_valid(&self) -> bool {
+        self.scan_lines != 0
+            && self.stripe_width != 0
+            && WIDTH.is_multiple_of(self.stripe_width)
+            && (HEIGHT / 2).is_multiple_of(self.scan_lines)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// Each pixel of the canvas is shifted out exactly once
+    pub(crate) fn assert_covers_canvas<const WIDTH: usize, const HEIGHT: usize>(
+        mapping: &dyn ScanMapping<WIDTH, HEIGHT>,
+    ) {
+        let mut seen = [[false; WIDTH]; HEIGHT];
+        assert_eq!(
+            2 * mapping.scan_lines() * mapping.chain_length(),
+            WIDTH * HEIGHT
+        );
+        for line in 0..mapping.scan_lines() {
+            for index in 0..mapping.chain_length() {
+                for half in [PanelHalf::Top, PanelHalf::Bottom] {
+                    let (x, y) = mapping.pixel_position(line, index, half);
+                    assert!(x < WIDTH && y < HEIGHT, "({x}, {y}) out of the canvas");
+                    assert!(!seen[y][x], "({x}, {y}) shifted out twice");
+                    seen[y][x] = true;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn half_scan_covers_canvas() {
+        assert_covers_canvas::<64, 32>(&HalfScan);
+        assert_covers_canvas::<64, 64>(&HalfScan);
+    }
+
+    #[test]
+    fn striped_scan_covers_canvas() {
+        for scan_lines in [2, 4, 8] {
+            for bottom_row_first in [false, true] {
+                for zigzag in [false, true] {
+                    let mapping = StripedScan {
+                        scan_lines,
+                        stripe_width: 8,
+                        bottom_row_first,
+                        zigzag,
+                    };
+                    assert_covers_canvas::<32, 16>(&mapping);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn striped_scan_must_fit_the_canvas() {
+        let mapping = |scan_lines, stripe_width| StripedScan {
+            scan_lines,
+            stripe_width,
+            bottom_row_first: false,
+            zigzag: false,
+        };
+        assert!(ScanMapping::<32, 16>::is_valid(&mapping(4, 8)));
+        assert!(ScanMapping::<32, 16>::is_valid(&mapping(8, 32)));
+        // Stripes not dividing the width
+        assert!(!ScanMapping::<32, 16>::is_valid(&mapping(4, 0)));
+        assert!(!ScanMapping::<32, 16>::is_valid(&mapping(4, 12)));
+        // Scan lines not dividing half the height
+        assert!(!ScanMapping::<32, 16>::is_valid(&mapping(0, 8)));
+        assert!(!ScanMapping::<32, 16>::is_valid(&mapping(3, 8)));
+        assert!(!ScanMapping::<32, 16>::is_valid(&mapping(16, 8)));
+    }
+
+    #[test]
+    fn striped_scan_positions() {
+        // 1/4 scan of a 32x16 panel: each address lights 2 rows of each half
+        let mapping = StripedScan {
+            scan_lines: 4,
+            stripe_width: 8,
+            bottom_row_first: true,
+            zigzag: true,
+        };
+        let position =
+            |index| ScanMapping::<32, 16>::pixel_position(&mapping, 1, index, PanelHalf::Top);
+        assert_eq!(position(0), (0, 5));
+        assert_eq!(position(7), (7, 5));
+        assert_eq!(position(8), (7, 1));
+        assert_eq!(position(15), (0, 1));
+        assert_eq!(position(16), (8, 5));
+        assert_eq!(
+            ScanMapping::<32, 16>::pixel_position(&mapping, 1, 0, PanelHalf::Bottom),
+            (0, 13)
+        );
+    }
+}
@@ -24,126 +24,576 @@ SOFTWARE.
 
 use core::cell::RefCell;
 
+use crate::bitplane::BitPlaneFrame;
 use crate::canvas::Canvas;
+use crate::chip::PanelChip;
+use crate::error::{Error, Result};
+use crate::exchange::{FrameExchange, FramePresenter};
+use crate::gamma::ColorCorrection;
 use crate::ledmatrix::{ColorBitPosition, LedMatrix};
-use crate::timer::Timer16Mhz;
-use crate::{enable_interrupts, log, MatrixTimer, MATRIX_TIMER_INTERRUPT};
+use crate::scan::ScanMapping;
+use crate::timer::{ScanTimer, Timer16Mhz};
+use crate::{enable_interrupts, log, panel, MatrixTimer};
 use cortex_m::interrupt::Mutex;
 
-use nrf52833_hal::pac::interrupt;
+/// Scheduled matrix in a static, shared with its timer interrupt.
+///
+/// Its frames are in the static too, so they are never built on the stack, and
+/// [`present`] converts a canvas without disabling the interrupts.
+pub struct SharedScheduledMatrix<
+    const L: usize,
+    const W: usize,
+    const H: usize,
+    T = Timer16Mhz<MatrixTimer>,
+> {
+    scheduled_matrix: Mutex<RefCell<Option<ScheduledLedMatrix<L, W, H, T>>>>,
+    frame_exchange: FrameExchange<W, H>,
+}
 
-pub type SharedScheduledMatrix<const L: usize, const W: usize, const H: usize> =
-    Mutex<RefCell<Option<ScheduledLedMatrix<L, W, H>>>>;
+impl<const L: usize, const W: usize, const H: usize, T> SharedScheduledMatrix<L, W, H, T> {
+    pub const fn new() -> Self {
+        SharedScheduledMatrix {
+            scheduled_matrix: Mutex::new(RefCell::new(None)),
+            frame_exchange: FrameExchange::new(),
+        }
+    }
+}
+
+impl<const L: usize, const W: usize, const H: usize, T> Default
+    for SharedScheduledMatrix<L, W, H, T>
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 pub type SharedScheduledMatrix64x32 = SharedScheduledMatrix<4, 64, 32>;
 
-static SCHEDULED_LED_MATRIX: SharedScheduledMatrix64x32 = Mutex::new(RefCell::new(None));
+pub type SharedScheduledMatrix64x64 = SharedScheduledMatrix<5, 64, 64>;
 
-const BCM_CYCLES_NB: u8 = 8; // min is 1
-const BCM_BASE_PERIOD_MICROSEC: u32 = 1;
+/// Scheduled matrix for the panel size selected in [`panel`]
+pub type SharedScheduledPanel =
+    SharedScheduledMatrix<{ panel::LINECTRL_PIN_COUNT }, { panel::WIDTH }, { panel::HEIGHT }>;
 
-#[interrupt]
-fn TIMER4() {
-    static mut CYCLE_STEP: u8 = 0;
-    static mut LINE_STEP: usize = 0;
+pub use crate::exchange::FRAME_BUFFERS_NB;
 
-    cortex_m::interrupt::free(|cs| {
-        let mut borrowed_led_matrix = SCHEDULED_LED_MATRIX.borrow(cs).borrow_mut();
-        let schedule_led_matrix = borrowed_led_matrix.as_mut().unwrap();
-        schedule_led_matrix.ack_interrupt();
-        schedule_led_matrix.display_line(
-            *LINE_STEP,
-            ColorBitPosition(*CYCLE_STEP + ColorBitPosition::MSB_POSITION - BCM_CYCLES_NB + 1),
+pub const DEFAULT_BCM_PLANES_NB: u8 = 8;
+pub const DEFAULT_BCM_BASE_PERIOD_MICROSEC: u32 = 1;
+
+/// Declare a static scheduled matrix of any size, and the interrupt of its timer.
+///
+///     scheduled_led_matrix!(static SCHEDULED_32X16: 3, 32, 16);
+///     let scheduled_led_matrix =
+///         ScheduledLedMatrix::init_shared(&SCHEDULED_32X16, led_matrix, Timer16Mhz::new(p.TIMER4));
+///
+/// `TIMER4` is used by default, another timer or a RTC can be selected:
+///
+///     scheduled_led_matrix!(static SCHEDULED_32X16: 3, 32, 16, timer = TIMER1);
+///     scheduled_led_matrix!(static SCHEDULED_32X16: 3, 32, 16, rtc = RTC1);
+///
+/// Its interrupt must not be declared elsewhere, and the `builtin-scheduler`
+/// feature, which declares the `TIMER4` one used by [`take_ref`](ScheduledLedMatrix::take_ref),
+/// must be disabled when using `TIMER4`.
+/// Sizes which are not literals are given between braces, as `{ panel::WIDTH }`.
+#[macro_export]
+macro_rules! scheduled_led_matrix {
+    ($vis:vis static $name:ident: $linectrl_pin_count:tt, $width:tt, $height:tt) => {
+        $crate::scheduled_led_matrix!(
+            $vis static $name: $linectrl_pin_count, $width, $height, timer = TIMER4
         );
-        /* TODO: Bug somewhere, but I would like to test the result
-        if *LINE_STEP >= schedule_led_matrix.half_height() {
-            *LINE_STEP = 0;
-            *CYCLE_STEP = (*CYCLE_STEP + 1_u8) % BCM_CYCLES_NB;
-        }
-        else {
-            *LINE_STEP += 1;
-        }
-        */
-
-        let next_int_delay = BCM_BASE_PERIOD_MICROSEC * 2_u32.pow(*CYCLE_STEP as u32);
-        schedule_led_matrix.schedule_next_interrupt(next_int_delay);
-
-        if *CYCLE_STEP >= BCM_CYCLES_NB - 1 {
-            *CYCLE_STEP = 0;
-            *LINE_STEP = (*LINE_STEP + 1) % schedule_led_matrix.half_height();
-            #[cfg(feature = "logging")]
-            {
-                if *LINE_STEP == 0 {
-                    let mut borrowed_draw_metrics =
-                        crate::metrics::DRAW_CYCLE_METRICS.borrow(cs).borrow_mut();
-                    if let Some(draw_metrics) = borrowed_draw_metrics.as_mut() {
-                        draw_metrics.inc_period();
-                    } else {
-                        log!("Issue, DRAW_CYCLE_METRICS is empty.");
-                    }
-                }
+    };
+    ($vis:vis static $name:ident: $linectrl_pin_count:tt, $width:tt, $height:tt, timer = $timer:ident) => {
+        $crate::scheduled_led_matrix!(
+            @declare $vis $name, $linectrl_pin_count, $width, $height,
+            $crate::timer::Timer16Mhz<$crate::__hal::pac::$timer>, $timer
+        );
+    };
+    ($vis:vis static $name:ident: $linectrl_pin_count:tt, $width:tt, $height:tt, rtc = $rtc:ident) => {
+        $crate::scheduled_led_matrix!(
+            @declare $vis $name, $linectrl_pin_count, $width, $height,
+            $crate::timer::RtcScanTimer<$crate::__hal::pac::$rtc>, $rtc
+        );
+    };
+    (@declare $vis:vis $name:ident, $linectrl_pin_count:tt, $width:tt, $height:tt, $timer:ty, $interrupt:ident) => {
+        $vis static $name: $crate::scheduler::SharedScheduledMatrix<
+            $linectrl_pin_count,
+            $width,
+            $height,
+            $timer,
+        > = $crate::scheduler::SharedScheduledMatrix::new();
+
+        const _: () = {
+            use $crate::__hal::pac::interrupt;
+
+            #[interrupt]
+            fn $interrupt() {
+                $crate::scheduler::on_shared_timer_interrupt(&$name);
+            }
+        };
+    };
+}
+
+#[cfg(feature = "builtin-scheduler")]
+scheduled_led_matrix!(static SCHEDULED_LED_MATRIX: { panel::LINECTRL_PIN_COUNT }, { panel::WIDTH }, { panel::HEIGHT });
+
+/// Body of the interrupt declared by [`scheduled_led_matrix!`]. On error, the
+/// rendering loop is stopped, see [`ScheduledLedMatrix::on_timer_interrupt`].
+#[allow(unused_variables)]
+pub fn on_shared_timer_interrupt<
+    const LINECTRL_PIN_COUNT: usize,
+    const WIDTH: usize,
+    const HEIGHT: usize,
+    T: ScanTimer,
+>(
+    shared: &SharedScheduledMatrix<LINECTRL_PIN_COUNT, WIDTH, HEIGHT, T>,
+) {
+    cortex_m::interrupt::free(|cs| {
+        // Empty once released
+        if let Some(schedule_led_matrix) = shared.scheduled_matrix.borrow(cs).borrow_mut().as_mut()
+        {
+            if let Err(err) = schedule_led_matrix.on_timer_interrupt() {
+                log!("Rendering loop stopped: {:?}", err);
             }
-        } else {
-            *CYCLE_STEP += 1;
         }
     });
 }
 
+/// Run `f` on the scheduled matrix of `shared`, in a critical section.
+pub fn with_scheduled_matrix<
+    const LINECTRL_PIN_COUNT: usize,
+    const WIDTH: usize,
+    const HEIGHT: usize,
+    T: ScanTimer,
+    R,
+>(
+    shared: &SharedScheduledMatrix<LINECTRL_PIN_COUNT, WIDTH, HEIGHT, T>,
+    f: impl FnOnce(&mut ScheduledLedMatrix<LINECTRL_PIN_COUNT, WIDTH, HEIGHT, T>) -> R,
+) -> Result<R> {
+    cortex_m::interrupt::free(|cs| {
+        let mut borrowed_scheduled_matrix = shared.scheduled_matrix.borrow(cs).borrow_mut();
+        let scheduled_matrix = borrowed_scheduled_matrix
+            .as_mut()
+            .ok_or(Error::NotInitialized)?;
+        Ok(f(scheduled_matrix))
+    })
+}
+
+/// Stop the rendering loop, empty `shared`, and give back the LED matrix and
+/// the timer, whose interrupt is masked.
+pub fn release<
+    const LINECTRL_PIN_COUNT: usize,
+    const WIDTH: usize,
+    const HEIGHT: usize,
+    T: ScanTimer,
+>(
+    shared: &SharedScheduledMatrix<LINECTRL_PIN_COUNT, WIDTH, HEIGHT, T>,
+) -> Result<(LedMatrix<LINECTRL_PIN_COUNT, WIDTH, HEIGHT>, T)> {
+    let scheduled_led_matrix =
+        cortex_m::interrupt::free(|cs| shared.scheduled_matrix.borrow(cs).take())
+            .ok_or(Error::NotInitialized)?;
+    nrf52833_hal::pac::NVIC::mask(T::INTERRUPT);
+    Ok(scheduled_led_matrix.release())
+}
+
+/// Queue `canvas` with [`try_present`](ScheduledLedMatrix::try_present), waiting for
+/// the end of the displayed frame if the previous presented canvas is still queued.
+/// With triple buffering, it replaces the queued canvas instead, and never waits.
+///
+/// The canvas is converted without disabling the interrupts, and never waits
+/// while the rendering loop is stopped. Fail if the presenter has been taken
+/// with [`take_presenter`](ScheduledLedMatrix::take_presenter).
+pub fn present<
+    const LINECTRL_PIN_COUNT: usize,
+    const WIDTH: usize,
+    const HEIGHT: usize,
+    T: ScanTimer,
+>(
+    shared: &SharedScheduledMatrix<LINECTRL_PIN_COUNT, WIDTH, HEIGHT, T>,
+    canvas: &Canvas<WIDTH, HEIGHT>,
+) -> Result<()> {
+    // Only the presenter is taken in the critical sections, for the time of the conversion
+    let mut presenter = with_scheduled_matrix(shared, |scheduled_led_matrix| {
+        scheduled_led_matrix.presenter.take()
+    })?
+    .ok_or(Error::PresenterTaken)?;
+    presenter.present(canvas);
+    with_scheduled_matrix(shared, |scheduled_led_matrix| {
+        scheduled_led_matrix.presenter = Some(presenter);
+    })
+}
+
+/// Order in which the lines and the BCM bit planes are displayed.
+#[derive(Copy, Clone, Default, PartialEq)]
+pub enum ScanOrder {
+    /// All the bit planes of a line, then the next line
+    #[default]
+    LineMajor,
+    /// All the lines for a bit plane, then the next bit plane
+    PlaneMajor,
+}
+
 pub struct ScheduledLedMatrix<
     const LINECTRL_PIN_COUNT: usize = 4,
     const WIDTH: usize = 64,
     const HEIGHT: usize = 32,
+    T = Timer16Mhz<MatrixTimer>,
 > {
-    front_canvas: Canvas<WIDTH, HEIGHT>,
+    /// Canvas of [`borrow_mut_canvas`](Self::borrow_mut_canvas), displayed right away when changed
+    canvas: Canvas<WIDTH, HEIGHT>,
+    /// Frames streamed to the panel by the interrupt, in a static so they are never moved
+    frame_exchange: &'static FrameExchange<WIDTH, HEIGHT>,
+    /// Converts the canvases into the frames, unless taken by the application
+    presenter: Option<FramePresenter<WIDTH, HEIGHT>>,
     led_matrix: LedMatrix<LINECTRL_PIN_COUNT, WIDTH, HEIGHT>,
-    timer: Timer16Mhz<MatrixTimer>,
+    timer: T,
+    scan_order: ScanOrder,
+    /// Number of most significant bits displayed for each color channel, from 1 to 8
+    bcm_planes_nb: u8,
+    /// Display duration of the least significant displayed bit
+    bcm_base_period_microsec: u32,
+    /// Part of each BCM slot during which the LEDs are on, 255 is the whole slot
+    brightness: u8,
+    line_step: usize,
+    cycle_step: u8,
+    /// Number of fully displayed frames, wrapping
+    frame_count: u32,
+    frame_callback: Option<fn(u32)>,
+    running: bool,
 }
 
-impl ScheduledLedMatrix<4, 64, 32> {
+// The frames are in the frame exchange: only the canvas is built on the stack,
+// whatever the panel size and the number of frame buffers. The rest is mostly
+// the color correction tables and the port masks, a few kilobytes.
+const _: () = assert!(
+    core::mem::size_of::<ScheduledLedMatrix<4, 64, 32>>()
+        < core::mem::size_of::<Canvas<64, 32>>() + 4096
+);
+const _: () = assert!(
+    core::mem::size_of::<ScheduledLedMatrix<5, 64, 64>>()
+        < core::mem::size_of::<Canvas<64, 64>>() + 4096
+);
+const _: () = assert!(
+    core::mem::size_of::<FrameExchange<64, 64>>()
+        < FRAME_BUFFERS_NB * core::mem::size_of::<BitPlaneFrame<64, 64>>() + 64
+);
+
+#[cfg(feature = "builtin-scheduler")]
+impl ScheduledLedMatrix<{ panel::LINECTRL_PIN_COUNT }, { panel::WIDTH }, { panel::HEIGHT }> {
+    /// Same as [`try_take_ref`](Self::try_take_ref), ignoring a second initialization.
     pub fn take_ref(
-        led_matrix: LedMatrix<4, 64, 32>,
+        led_matrix: LedMatrix<{ panel::LINECTRL_PIN_COUNT }, { panel::WIDTH }, { panel::HEIGHT }>,
         timer: Timer16Mhz<MatrixTimer>,
-    ) -> &'static SharedScheduledMatrix64x32 {
+    ) -> &'static SharedScheduledPanel {
+        Self::init_shared(&SCHEDULED_LED_MATRIX, led_matrix, timer)
+    }
+
+    /// Fill the built-in scheduled matrix, driven by `TIMER4`.
+    pub fn try_take_ref(
+        led_matrix: LedMatrix<{ panel::LINECTRL_PIN_COUNT }, { panel::WIDTH }, { panel::HEIGHT }>,
+        timer: Timer16Mhz<MatrixTimer>,
+    ) -> Result<&'static SharedScheduledPanel> {
+        Self::try_init_shared(&SCHEDULED_LED_MATRIX, led_matrix, timer)
+    }
+}
+
+impl<const LINECTRL_PIN_COUNT: usize, const WIDTH: usize, const HEIGHT: usize, T: ScanTimer>
+    ScheduledLedMatrix<LINECTRL_PIN_COUNT, WIDTH, HEIGHT, T>
+{
+    /// Scheduled matrix owned by the caller, for instance a RTIC resource. The
+    /// interrupt of `timer` must be unmasked, and its handler must call
+    /// [`on_timer_interrupt`](Self::on_timer_interrupt). The frames are the
+    /// ones of `frame_exchange`: fail if its presenter is already taken.
+    pub fn new(
+        led_matrix: LedMatrix<LINECTRL_PIN_COUNT, WIDTH, HEIGHT>,
+        timer: T,
+        frame_exchange: &'static FrameExchange<WIDTH, HEIGHT>,
+    ) -> Result<Self> {
+        let presenter = frame_exchange
+            .take_presenter(*led_matrix.color_correction(), led_matrix.scan_mapping())
+            .ok_or(Error::PresenterTaken)?;
+        Ok(ScheduledLedMatrix {
+            led_matrix,
+            canvas: Canvas::new(),
+            frame_exchange,
+            presenter: Some(presenter),
+            timer,
+            scan_order: Default::default(),
+            bcm_planes_nb: DEFAULT_BCM_PLANES_NB,
+            bcm_base_period_microsec: DEFAULT_BCM_BASE_PERIOD_MICROSEC.max(T::MIN_PERIOD_MICROSEC),
+            brightness: u8::MAX,
+            line_step: 0,
+            cycle_step: 0,
+            frame_count: 0,
+            frame_callback: None,
+            running: false,
+        })
+    }
+
+    /// Same as [`try_init_shared`](Self::try_init_shared), ignoring a second
+    /// initialization: `led_matrix` and `timer` are then dropped. Panic on the
+    /// other errors, such as a presenter already taken from the static.
+    pub fn init_shared(
+        shared: &'static SharedScheduledMatrix<LINECTRL_PIN_COUNT, WIDTH, HEIGHT, T>,
+        led_matrix: LedMatrix<LINECTRL_PIN_COUNT, WIDTH, HEIGHT>,
+        timer: T,
+    ) -> &'static SharedScheduledMatrix<LINECTRL_PIN_COUNT, WIDTH, HEIGHT, T> {
+        match Self::try_init_shared(shared, led_matrix, timer) {
+            Ok(_) | Err(Error::AlreadyInitialized) => shared,
+            Err(error) => panic!("Cannot initialize the scheduled matrix: {:?}", error),
+        }
+    }
+
+    /// Fill a static declared with [`scheduled_led_matrix!`], and unmask the
+    /// interrupt of `timer`. Fail if the static is already filled.
+    pub fn try_init_shared(
+        shared: &'static SharedScheduledMatrix<LINECTRL_PIN_COUNT, WIDTH, HEIGHT, T>,
+        led_matrix: LedMatrix<LINECTRL_PIN_COUNT, WIDTH, HEIGHT>,
+        timer: T,
+    ) -> Result<&'static SharedScheduledMatrix<LINECTRL_PIN_COUNT, WIDTH, HEIGHT, T>> {
         cortex_m::interrupt::free(|cs| {
-            let borrowed_scheduled_matrix = SCHEDULED_LED_MATRIX.borrow(cs);
+            let borrowed_scheduled_matrix = shared.scheduled_matrix.borrow(cs);
             if borrowed_scheduled_matrix.borrow().is_some() {
-                return;
+                return Err(Error::AlreadyInitialized);
             }
-            enable_interrupts!(MATRIX_TIMER_INTERRUPT);
-            let scheduled_let_matrix = ScheduledLedMatrix {
-                led_matrix,
-                front_canvas: Default::default(),
-                timer,
-            };
-            borrowed_scheduled_matrix.replace(Some(scheduled_let_matrix));
-        });
-        &SCHEDULED_LED_MATRIX
+            let scheduled_matrix =
+                ScheduledLedMatrix::new(led_matrix, timer, &shared.frame_exchange)?;
+            enable_interrupts!(T::INTERRUPT);
+            borrowed_scheduled_matrix.replace(Some(scheduled_matrix));
+            Ok(shared)
+        })
     }
 }
 
-impl<const LINECTRL_PIN_COUNT: usize, const WIDTH: usize, const HEIGHT: usize>
-    ScheduledLedMatrix<LINECTRL_PIN_COUNT, WIDTH, HEIGHT>
+impl<const LINECTRL_PIN_COUNT: usize, const WIDTH: usize, const HEIGHT: usize, T: ScanTimer>
+    ScheduledLedMatrix<LINECTRL_PIN_COUNT, WIDTH, HEIGHT, T>
 {
     pub fn half_height(&self) -> usize {
         HEIGHT / 2
     }
 
+    /// Number of lines displayed for a whole frame, `half_height` for the default mapping
+    pub fn scan_lines(&self) -> usize {
+        self.led_matrix.scan_lines()
+    }
+
     // fn start_rendering_loop(self) -> Self<started>
-    pub fn start_rendering_loop(&mut self) {
+    /// Also resume a stopped loop, from the first line of a frame.
+    pub fn start_rendering_loop(&mut self) -> Result<()> {
+        if self.running {
+            return Ok(());
+        }
         log!("Start rendering loop");
-        self.schedule_next_interrupt(BCM_BASE_PERIOD_MICROSEC);
+        self.led_matrix.init_panel_chip()?;
+        self.running = true;
+        self.frame_exchange.set_scanning(true);
+        self.restart_scan();
+        self.schedule_next_interrupt(T::microsec_to_ticks(self.bcm_base_period_microsec))
     }
 
-    pub fn swap_canvas(&mut self, canvas: &mut Canvas<WIDTH, HEIGHT>) {
-        core::mem::swap(&mut self.front_canvas, canvas);
+    /// No more interrupt, and the LEDs are switched off. Without a regular
+    /// change of the address lines, some panels shut down, and may need their
+    /// chip to be initialized again, which [`start_rendering_loop`](Self::start_rendering_loop) does.
+    pub fn stop_rendering_loop(&mut self) -> Result<()> {
+        log!("Stop rendering loop");
+        self.running = false;
+        self.frame_exchange.set_scanning(false);
+        self.timer.disable_interrupt();
+        self.timer.disable_blanking();
+        self.led_matrix.disable_output()
+    }
+
+    pub fn is_rendering(&self) -> bool {
+        self.running
+    }
+
+    /// Switch off the LEDs, while the lines keep being scanned so the panel does not
+    /// shut down. The frames keep being swapped, and the frame callback called.
+    pub fn blank(&mut self, blanked: bool) -> Result<()> {
+        self.led_matrix.set_blanked(blanked)
+    }
+
+    pub fn is_blanked(&self) -> bool {
+        self.led_matrix.is_blanked()
+    }
+
+    /// Stop the rendering loop, and give back the LED matrix and the timer.
+    /// See [`release`] to take it out of a shared static.
+    pub fn release(mut self) -> (LedMatrix<LINECTRL_PIN_COUNT, WIDTH, HEIGHT>, T) {
+        // The LED matrix is given back even if OE could not be set
+        let _ = self.stop_rendering_loop();
+        (self.led_matrix, self.timer)
+    }
+
+    /// The scan restarts from the first line and bit plane.
+    pub fn set_scan_order(&mut self, scan_order: ScanOrder) {
+        self.scan_order = scan_order;
+        self.restart_scan();
+    }
+
+    /// Fewer bit planes means fewer colors, but a higher refresh rate. Clamped between 1 and 8.
+    pub fn set_bcm_planes_nb(&mut self, bcm_planes_nb: u8) {
+        self.bcm_planes_nb = bcm_planes_nb.clamp(1, ColorBitPosition::MSB_POSITION + 1);
+        self.restart_scan();
+    }
+
+    /// Display duration of the least significant displayed bit, each next bit doubles it.
+    /// Fail if it is shorter than the timer allows, as with a RTC, or if the slot of
+    /// the most significant bit is too long for the timer.
+    pub fn set_bcm_base_period_microsec(&mut self, period_microsec: u32) -> Result<()> {
+        let longest_slot_shift = ColorBitPosition::MSB_POSITION as u32;
+        if period_microsec < T::MIN_PERIOD_MICROSEC
+            || period_microsec > T::MAX_PERIOD_MICROSEC >> longest_slot_shift
+        {
+            return Err(Error::TimerPeriod);
+        }
+        self.bcm_base_period_microsec = period_microsec;
+        Ok(())
     }
 
-    pub fn copy_canvas(&mut self, canvas: &Canvas<WIDTH, HEIGHT>) {
-        self.front_canvas = canvas.clone();
+    /// Dim the whole panel by shortening the time OE is low in each BCM slot;
+    /// unlike [`Canvas::apply_luminosity`], all the bit planes are kept.
+    pub fn set_brightness(&mut self, brightness: u8) {
+        self.brightness = brightness;
     }
 
+    pub fn brightness(&self) -> u8 {
+        self.brightness
+    }
+
+    pub fn scan_order(&self) -> ScanOrder {
+        self.scan_order
+    }
+
+    pub fn bcm_planes_nb(&self) -> u8 {
+        self.bcm_planes_nb
+    }
+
+    pub fn bcm_base_period_microsec(&self) -> u32 {
+        self.bcm_base_period_microsec
+    }
+
+    /// Displayed right away, possibly in the middle of a frame; see [`try_present`](Self::try_present)
+    /// to wait for the end of the displayed frame. Once the presenter is taken, the
+    /// canvases are still swapped but nothing is displayed, and it fails with
+    /// [`Error::PresenterTaken`].
+    pub fn swap_canvas(&mut self, canvas: &mut Canvas<WIDTH, HEIGHT>) -> Result<()> {
+        core::mem::swap(&mut self.canvas, canvas);
+        self.update_front_frame()
+    }
+
+    /// Same as [`swap_canvas`](Self::swap_canvas), keeping `canvas`
+    pub fn copy_canvas(&mut self, canvas: &Canvas<WIDTH, HEIGHT>) -> Result<()> {
+        self.canvas.clone_from(canvas);
+        self.update_front_frame()
+    }
+
+    /// Queue `canvas`, converted right away, and displayed once the last line of the
+    /// last bit plane of the current frame has been displayed, so the panel never
+    /// shows a mix of both. Without a rendering loop, it is displayed right away.
+    ///
+    /// Return false if the previous presented canvas is still queued: `canvas` is then
+    /// ignored, unless with triple buffering, where it replaces the queued one. Also
+    /// return false once the presenter has been taken.
+    pub fn try_present(&mut self, canvas: &Canvas<WIDTH, HEIGHT>) -> bool {
+        self.presenter
+            .as_mut()
+            .is_some_and(|presenter| presenter.try_present(canvas))
+    }
+
+    /// Number of frames fully displayed since the start, wrapping at `u32::MAX`
+    pub fn frame_count(&self) -> u32 {
+        self.frame_count
+    }
+
+    /// Called with the frame count after each fully displayed frame, once the
+    /// presented canvas, if any, has become the front one. It runs in the timer
+    /// interrupt, in a critical section, so it must be short.
+    pub fn set_frame_callback(&mut self, frame_callback: Option<fn(u32)>) {
+        self.frame_callback = frame_callback;
+    }
+
+    /// Give the presenter of the frames to the application, which then converts and
+    /// presents its canvases without disabling the interrupts, or without locking
+    /// the scheduled matrix. The canvas methods of this scheduled matrix then fail
+    /// with [`Error::PresenterTaken`]. Return `None` if already taken.
+    pub fn take_presenter(&mut self) -> Option<FramePresenter<WIDTH, HEIGHT>> {
+        self.presenter.take()
+    }
+
+    /// True while a presented canvas waits for the end of the displayed frame
+    pub fn is_present_pending(&self) -> bool {
+        self.frame_exchange.is_present_pending()
+    }
+
+    /// Applied to the next converted canvases, the canvas of
+    /// [`borrow_mut_canvas`](Self::borrow_mut_canvas) being displayed again with it.
+    /// Once the presenter is taken, set it on the presenter instead.
+    pub fn set_color_correction(&mut self, color_correction: ColorCorrection) {
+        self.led_matrix.set_color_correction(color_correction);
+        if let Some(presenter) = self.presenter.as_mut() {
+            presenter.set_color_correction(color_correction);
+            self.convert_front_frame();
+        }
+    }
+
+    /// The chip is initialized right away, and again when the rendering loop starts.
+    pub fn set_panel_chip(&mut self, panel_chip: PanelChip) -> Result<()> {
+        self.led_matrix.set_panel_chip(panel_chip);
+        self.led_matrix.init_panel_chip()
+    }
+
+    /// The canvas of [`borrow_mut_canvas`](Self::borrow_mut_canvas) is displayed again
+    /// with the new mapping, from the first line, and a queued canvas is dropped.
+    /// Fail once the presenter is taken, as it keeps the mapping it was taken with.
+    pub fn set_scan_mapping(
+        &mut self,
+        scan_mapping: &'static dyn ScanMapping<WIDTH, HEIGHT>,
+    ) -> Result<()> {
+        let Some(presenter) = self.presenter.as_mut() else {
+            return Err(Error::PresenterTaken);
+        };
+        presenter.set_scan_mapping(scan_mapping);
+        self.led_matrix.set_scan_mapping(scan_mapping);
+        // Converted with the previous mapping, the queued frame cannot be displayed
+        self.frame_exchange.drop_pending();
+        self.convert_front_frame();
+        self.restart_scan();
+        Ok(())
+    }
+
+    /// The changes are displayed once [`commit_canvas`](Self::commit_canvas) is called.
+    /// The presented canvases are not kept here.
     pub fn borrow_mut_canvas(&mut self) -> &mut Canvas<WIDTH, HEIGHT> {
-        &mut self.front_canvas
+        &mut self.canvas
+    }
+
+    /// Convert the canvas changed through [`borrow_mut_canvas`](Self::borrow_mut_canvas)
+    /// into the displayed frame, right away, possibly in the middle of a frame.
+    /// Fail with [`Error::PresenterTaken`] once the presenter is taken, nothing
+    /// being displayed.
+    pub fn commit_canvas(&mut self) -> Result<()> {
+        self.update_front_frame()
+    }
+
+    /// Convert the canvas into the displayed frame, unless the presenter is taken
+    fn update_front_frame(&mut self) -> Result<()> {
+        if self.presenter.is_none() {
+            return Err(Error::PresenterTaken);
+        }
+        self.convert_front_frame();
+        Ok(())
+    }
+
+    /// Only while the presenter is not taken
+    fn convert_front_frame(&mut self) {
+        // Safety: the scan interrupt needs this scheduled matrix to display the
+        // front frame, and the presenter, which never writes it, is not taken.
+        let frame = unsafe { self.frame_exchange.front_frame_mut() };
+        frame.update_from_canvas(
+            &self.canvas,
+            self.led_matrix.color_correction(),
+            self.led_matrix.scan_mapping(),
+        );
     }
 
     pub fn ack_interrupt(&mut self) {
@@ -152,16 +602,115 @@ impl<const LINECTRL_PIN_COUNT: usize, const WIDTH: usize, const HEIGHT: usize>
 
     // fn refresh_display(&mut self) {
     //     self.led_matrix
-    //         .draw_canvas_with_delay_buffer(&self.front_canvas, Some(&mut self.timer));
+    //         .draw_canvas_with_delay_buffer(&self.canvases[self.front], Some(&mut self.timer));
     // }
 
-    fn display_line(&mut self, line: usize, bit_position: ColorBitPosition) {
+    /// Display the next line, to call from the interrupt of the timer.
+    /// On error, the rendering loop is stopped.
+    pub fn on_timer_interrupt(&mut self) -> Result<()> {
+        if !self.running {
+            // Interrupt already pending when stopped
+            self.ack_interrupt();
+            return Ok(());
+        }
+        let result = self.scan_next_step();
+        if result.is_err() {
+            let _ = self.stop_rendering_loop();
+        }
+        result
+    }
+
+    fn scan_next_step(&mut self) -> Result<()> {
+        if self.timer.take_blanking_event() {
+            // End of the lit part of the current slot, the next line is already scheduled
+            return self.led_matrix.disable_output();
+        }
+        self.ack_interrupt();
+        // Only the `bcm_planes_nb` most significant bits are displayed
+        let lowest_bit_position = ColorBitPosition::MSB_POSITION + 1 - self.bcm_planes_nb;
+        self.display_line(
+            self.line_step,
+            ColorBitPosition(lowest_bit_position + self.cycle_step),
+        )?;
+        // The base period is converted first, so the slots keep exact BCM weights
+        // even if the timer ticks do not divide it
+        let next_int_ticks = T::microsec_to_ticks(self.bcm_base_period_microsec) << self.cycle_step;
+        self.schedule_next_interrupt(next_int_ticks)?;
+
+        if self.advance_scan_step() {
+            self.on_frame_completed();
+        }
+        Ok(())
+    }
+
+    fn on_frame_completed(&mut self) {
+        self.frame_exchange.swap_pending();
+        self.frame_count = self.frame_count.wrapping_add(1);
+        if let Some(frame_callback) = self.frame_callback {
+            frame_callback(self.frame_count);
+        }
+        #[cfg(feature = "logging")]
+        cortex_m::interrupt::free(|cs| {
+            let mut borrowed_draw_metrics =
+                crate::metrics::DRAW_CYCLE_METRICS.borrow(cs).borrow_mut();
+            if let Some(draw_metrics) = borrowed_draw_metrics.as_mut() {
+                draw_metrics.inc_period();
+            } else {
+                log!("Issue, DRAW_CYCLE_METRICS is empty.");
+            }
+        });
+    }
+
+    /// Move to the next line or bit plane, return true if the last line of the
+    /// last bit plane has just been displayed.
+    fn advance_scan_step(&mut self) -> bool {
+        let last_line = self.line_step + 1 >= self.scan_lines();
+        let last_cycle = self.cycle_step + 1 >= self.bcm_planes_nb;
+        match self.scan_order {
+            ScanOrder::LineMajor => {
+                if last_cycle {
+                    self.cycle_step = 0;
+                    self.line_step = if last_line { 0 } else { self.line_step + 1 };
+                } else {
+                    self.cycle_step += 1;
+                }
+            }
+            ScanOrder::PlaneMajor => {
+                if last_line {
+                    self.line_step = 0;
+                    self.cycle_step = if last_cycle { 0 } else { self.cycle_step + 1 };
+                } else {
+                    self.line_step += 1;
+                }
+            }
+        }
+        last_line && last_cycle
+    }
+
+    fn restart_scan(&mut self) {
+        self.line_step = 0;
+        self.cycle_step = 0;
+    }
+
+    fn display_line(&mut self, line: usize, bit_position: ColorBitPosition) -> Result<()> {
         self.led_matrix
-            .draw_canvas_line(&self.front_canvas, line, bit_position);
+            .draw_frame_line(self.frame_exchange.front_frame(), line, bit_position)
     }
 
-    fn schedule_next_interrupt(&mut self, delay_microsec: u32) {
-        self.timer.start(delay_microsec);
+    fn schedule_next_interrupt(&mut self, delay_ticks: u32) -> Result<()> {
+        match self.brightness {
+            u8::MAX => self.timer.disable_blanking(),
+            0 => {
+                self.timer.disable_blanking();
+                self.led_matrix.disable_output()?;
+            }
+            brightness => {
+                let lit_ticks = delay_ticks as u64 * brightness as u64 / u8::MAX as u64;
+                self.timer.enable_blanking((lit_ticks as u32).max(1));
+            }
+        }
+        self.timer.start(delay_ticks);
         self.timer.enable_interrupt();
+        Ok(())
     }
 }
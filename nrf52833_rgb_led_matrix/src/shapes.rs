@@ -0,0 +1,651 @@
+/*
+MIT License
+
+Copyright (c) 2026 Vincent Hiribarren
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Lines, circles, ellipses, arcs, rectangles and polygons drawn on a [`Canvas`].
+//!
+//! Unlike the other drawing methods, the shapes take signed coordinates and
+//! can lie partly outside of the canvas: they are clipped to its bounds. As
+//! for [`Canvas::draw_rectangle`], the `draw_*` methods fill the shapes, the
+//! `draw_*_outline` ones only draw their one pixel wide border. Angles are in
+//! degrees, clockwise from the right, the y axis pointing down:
+//!
+//! ```ignore
+//! canvas
+//!     .draw_thick_line(0, 31, 63, 0, 3, Color::WHITE)
+//!     .draw_circle_outline(32, 16, 10, Color::RED)
+//!     .draw_arc(32, 16, 8, -90, 0, Color::YELLOW)
+//!     .draw_polygon(&[(-4, 0), (10, 2), (4, 12)], Color::GREEN);
+//! ```
+
+use core::cmp::{max, min};
+
+use crate::canvas::{Canvas, Color};
+
+/// Sine of 0 to 90 degrees, scaled by 2^14
+const SIN_TABLE: [i16; 91] = [
+    0, 286, 572, 857, 1143, 1428, 1713, 1997, 2280, 2563, 2845, 3126, 3406, 3686, 3964, 4240, 4516,
+    4790, 5063, 5334, 5604, 5872, 6138, 6402, 6664, 6924, 7182, 7438, 7692, 7943, 8192, 8438, 8682,
+    8923, 9162, 9397, 9630, 9860, 10087, 10311, 10531, 10749, 10963, 11174, 11381, 11585, 11786,
+    11982, 12176, 12365, 12551, 12733, 12911, 13085, 13255, 13421, 13583, 13741, 13894, 14044,
+    14189, 14330, 14466, 14598, 14726, 14849, 14968, 15082, 15191, 15296, 15396, 15491, 15582,
+    15668, 15749, 15826, 15897, 15964, 16026, 16083, 16135, 16182, 16225, 16262, 16294, 16322,
+    16344, 16362, 16374, 16382, 16384,
+];
+
+fn sin_deg(angle: i32) -> i64 {
+    let angle = angle.rem_euclid(360);
+    let value = match angle {
+        0..=90 => SIN_TABLE[angle as usize],
+        91..=180 => SIN_TABLE[(180 - angle) as usize],
+        181..=270 => -SIN_TABLE[(angle - 180) as usize],
+        _ => -SIN_TABLE[(360 - angle) as usize],
+    };
+    value as i64
+}
+
+fn cos_deg(angle: i32) -> i64 {
+    sin_deg(angle.rem_euclid(360) + 90)
+}
+
+fn isqrt(n: u128) -> u128 {
+    if n < 2 {
+        return n;
+    }
+    let mut x = n;
+    let mut y = n / 2;
+    while y < x {
+        x = y;
+        y = (x + n / x) / 2;
+    }
+    x
+}
+
+/// Division rounded to the nearest integer, `den` being positive
+fn div_round(num: i128, den: i128) -> i128 {
+    (2 * num + den).div_euclid(2 * den)
+}
+
+/// Angular sector going clockwise from a start angle to an end angle
+struct Sector {
+    start: (i64, i64),
+    end: (i64, i64),
+    sweep: i64,
+}
+
+impl Sector {
+    fn new(start_angle: i32, end_angle: i32) -> Self {
+        let sweep = end_angle as i64 - start_angle as i64;
+        Sector {
+            start: (cos_deg(start_angle), sin_deg(start_angle)),
+            end: (cos_deg(end_angle), sin_deg(end_angle)),
+            sweep: if sweep >= 360 {
+                360
+            } else {
+                sweep.rem_euclid(360)
+            },
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.sweep == 0
+    }
+
+    fn contains(&self, dx: i64, dy: i64) -> bool {
+        if self.sweep >= 360 || (dx, dy) == (0, 0) {
+            return true;
+        }
+        // Positive when b is clockwise from a, the y axis pointing down
+        let cross = |a: (i64, i64), b: (i64, i64)| a.0 * b.1 - a.1 * b.0;
+        let point = (dx, dy);
+        if self.sweep <= 180 {
+            cross(self.start, point) >= 0 && cross(point, self.end) >= 0
+        } else {
+            !(cross(self.end, point) > 0 && cross(point, self.start) > 0)
+        }
+    }
+}
+
+/// Midpoint circle, calls `plot(x, y)` for each point of the octant where x >= y >= 0
+fn circle_octant(radius: u16, mut plot: impl FnMut(i64, i64)) {
+    let (mut x, mut y) = (radius as i64, 0);
+    let mut error = 1 - x;
+    while x >= y {
+        plot(x, y);
+        y += 1;
+        if error < 0 {
+            error += 2 * y + 1;
+        } else {
+            x -= 1;
+            error += 2 * (y - x) + 1;
+        }
+    }
+}
+
+/// Midpoint ellipse, calls `plot(x, y)` for each point of the quadrant where x, y >= 0
+fn ellipse_quadrant(radius_x: u16, radius_y: u16, mut plot: impl FnMut(i64, i64)) {
+    let (a, b) = (radius_x as i128, radius_y as i128);
+    if b == 0 {
+        for x in 0..=a {
+            plot(x as i64, 0);
+        }
+        return;
+    }
+    let (a2, b2) = (a * a, b * b);
+    let (mut x, mut y) = (0, b);
+    let (mut dx, mut dy) = (0, 2 * a2 * y);
+    // Decision variables are scaled by 4, to stay on integers
+    let mut decision = 4 * b2 - 4 * a2 * b + a2;
+    while dx < dy {
+        plot(x as i64, y as i64);
+        x += 1;
+        dx += 2 * b2;
+        if decision < 0 {
+            decision += 4 * (dx + b2);
+        } else {
+            y -= 1;
+            dy -= 2 * a2;
+            decision += 4 * (dx - dy + b2);
+        }
+    }
+    decision = b2 * (2 * x + 1) * (2 * x + 1) + 4 * a2 * (y - 1) * (y - 1) - 4 * a2 * b2;
+    while y >= 0 {
+        plot(x as i64, y as i64);
+        y -= 1;
+        dy -= 2 * a2;
+        if decision > 0 {
+            decision += 4 * (a2 - dy);
+        } else {
+            x += 1;
+            dx += 2 * b2;
+            decision += 4 * (dx - dy + a2);
+        }
+    }
+}
+
+impl<const WIDTH: usize, const HEIGHT: usize> Canvas<WIDTH, HEIGHT> {
+    fn plot(&mut self, x: i64, y: i64, color: Color) {
+        if (0..WIDTH as i64).contains(&x) && (0..HEIGHT as i64).contains(&y) {
+            self.0[y as usize][x as usize] = color;
+        }
+    }
+
+    /// Horizontal span between `x0` and `x1` included, where `keep(x)` holds
+    fn plot_span_where(
+        &mut self,
+        x0: i64,
+        x1: i64,
+        y: i64,
+        color: Color,
+        keep: impl Fn(i64) -> bool,
+    ) {
+        if !(0..HEIGHT as i64).contains(&y) {
+            return;
+        }
+        let start = max(min(x0, x1), 0);
+        let end = min(max(x0, x1), WIDTH as i64 - 1);
+        for x in start..=end {
+            if keep(x) {
+                self.0[y as usize][x as usize] = color;
+            }
+        }
+    }
+
+    fn plot_span(&mut self, x0: i64, x1: i64, y: i64, color: Color) {
+        self.plot_span_where(x0, x1, y, color, |_| true);
+    }
+
+    fn plot_vertical_span(&mut self, x: i64, y0: i64, y1: i64, color: Color) {
+        if !(0..WIDTH as i64).contains(&x) {
+            return;
+        }
+        let start = max(min(y0, y1), 0);
+        let end = min(max(y0, y1), HEIGHT as i64 - 1);
+        for y in start..=end {
+            self.0[y as usize][x as usize] = color;
+        }
+    }
+
+    pub fn draw_line(&mut self, x0: i32, y0: i32, x1: i32, y1: i32, color: Color) -> &mut Self {
+        self.draw_thick_line(x0, y0, x1, y1, 1, color)
+    }
+
+    /// Bresenham line, `thickness` being measured perpendicularly to the line
+    pub fn draw_thick_line(
+        &mut self,
+        x0: i32,
+        y0: i32,
+        x1: i32,
+        y1: i32,
+        thickness: u16,
+        color: Color,
+    ) -> &mut Self {
+        if thickness == 0 {
+            return self;
+        }
+        let x_major = (x1 as i64 - x0 as i64).abs() >= (y1 as i64 - y0 as i64).abs();
+        // Iterate along the major axis u, the minor axis being v
+        let (u0, v0, u1, v1, u_size) = if x_major {
+            (x0, y0, x1, y1, WIDTH)
+        } else {
+            (y0, x0, y1, x1, HEIGHT)
+        };
+        let ((u0, v0), (u1, v1)) = if u0 <= u1 {
+            ((u0 as i128, v0 as i128), (u1 as i128, v1 as i128))
+        } else {
+            ((u1 as i128, v1 as i128), (u0 as i128, v0 as i128))
+        };
+        let (du, dv) = (u1 - u0, v1 - v0);
+        // Width of the spans along the minor axis, to get the expected thickness
+        let thickness = thickness as i128;
+        let span = match du {
+            0 => thickness,
+            _ => {
+                let length = isqrt((thickness * thickness * (du * du + dv * dv)) as u128) as i128;
+                max((length + du / 2) / du, 1)
+            }
+        };
+        for u in max(u0, 0)..=min(u1, u_size as i128 - 1) {
+            let v = match du {
+                0 => v0,
+                _ => v0 + div_round((u - u0) * dv, du),
+            };
+            let (v_start, v_end) = ((v - (span - 1) / 2) as i64, (v + span / 2) as i64);
+            if x_major {
+                self.plot_vertical_span(u as i64, v_start, v_end, color);
+            } else {
+                self.plot_span(v_start, v_end, u as i64, color);
+            }
+        }
+        self
+    }
+
+    pub fn draw_rectangle_outline(
+        &mut self,
+        x: i32,
+        y: i32,
+        width: u32,
+        height: u32,
+        color: Color,
+    ) -> &mut Self {
+        if width == 0 || height == 0 {
+            return self;
+        }
+        let (left, top) = (x as i64, y as i64);
+        let (right, bottom) = (left + width as i64 - 1, top + height as i64 - 1);
+        self.plot_span(left, right, top, color);
+        self.plot_span(left, right, bottom, color);
+        self.plot_vertical_span(left, top, bottom, color);
+        self.plot_vertical_span(right, top, bottom, color);
+        self
+    }
+
+    /// Filled rectangle with quarter circle corners, `radius` being reduced to fit
+    pub fn draw_rounded_rectangle(
+        &mut self,
+        x: i32,
+        y: i32,
+        width: u32,
+        height: u32,
+        radius: u16,
+        color: Color,
+    ) -> &mut Self {
+        let Some((left, top, right, bottom, radius)) = rounded_bounds(x, y, width, height, radius)
+        else {
+            return self;
+        };
+        for line in top..=bottom {
+            self.plot_span(left - radius, right + radius, line, color);
+        }
+        circle_octant(radius as u16, |dx, dy| {
+            self.plot_span(left - dx, right + dx, top - dy, color);
+            self.plot_span(left - dy, right + dy, top - dx, color);
+            self.plot_span(left - dx, right + dx, bottom + dy, color);
+            self.plot_span(left - dy, right + dy, bottom + dx, color);
+        });
+        self
+    }
+
+    pub fn draw_rounded_rectangle_outline(
+        &mut self,
+        x: i32,
+        y: i32,
+        width: u32,
+        height: u32,
+        radius: u16,
+        color: Color,
+    ) -> &mut Self {
+        let Some((left, top, right, bottom, radius)) = rounded_bounds(x, y, width, height, radius)
+        else {
+            return self;
+        };
+        self.plot_span(left, right, top - radius, color);
+        self.plot_span(left, right, bottom + radius, color);
+        self.plot_vertical_span(left - radius, top, bottom, color);
+        self.plot_vertical_span(right + radius, top, bottom, color);
+        circle_octant(radius as u16, |dx, dy| {
+            for (a, b) in [(dx, dy), (dy, dx)] {
+                self.plot(left - a, top - b, color);
+                self.plot(right + a, top - b, color);
+                self.plot(left - a, bottom + b, color);
+                self.plot(right + a, bottom + b, color);
+            }
+        });
+        self
+    }
+
+    pub fn draw_circle(&mut self, cx: i32, cy: i32, radius: u16, color: Color) -> &mut Self {
+        self.draw_arc(cx, cy, radius, 0, 360, color)
+    }
+
+    pub fn draw_circle_outline(
+        &mut self,
+        cx: i32,
+        cy: i32,
+        radius: u16,
+        color: Color,
+    ) -> &mut Self {
+        self.draw_arc_outline(cx, cy, radius, 0, 360, color)
+    }
+
+    pub fn draw_ellipse(
+        &mut self,
+        cx: i32,
+        cy: i32,
+        radius_x: u16,
+        radius_y: u16,
+        color: Color,
+    ) -> &mut Self {
+        let (cx, cy) = (cx as i64, cy as i64);
+        ellipse_quadrant(radius_x, radius_y, |dx, dy| {
+            self.plot_span(cx - dx, cx + dx, cy - dy, color);
+            self.plot_span(cx - dx, cx + dx, cy + dy, color);
+        });
+        self
+    }
+
+    pub fn draw_ellipse_outline(
+        &mut self,
+        cx: i32,
+        cy: i32,
+        radius_x: u16,
+        radius_y: u16,
+        color: Color,
+    ) -> &mut Self {
+        let (cx, cy) = (cx as i64, cy as i64);
+        ellipse_quadrant(radius_x, radius_y, |dx, dy| {
+            self.plot(cx - dx, cy - dy, color);
+            self.plot(cx + dx, cy - dy, color);
+            self.plot(cx - dx, cy + dy, color);
+            self.plot(cx + dx, cy + dy, color);
+        });
+        self
+    }
+
+    /// Pie slice going clockwise from `start_angle` to `end_angle`
+    pub fn draw_arc(
+        &mut self,
+        cx: i32,
+        cy: i32,
+        radius: u16,
+        start_angle: i32,
+        end_angle: i32,
+        color: Color,
+    ) -> &mut Self {
+        let sector = Sector::new(start_angle, end_angle);
+        if sector.is_empty() {
+            return self;
+        }
+        let (cx, cy) = (cx as i64, cy as i64);
+        circle_octant(radius, |dx, dy| {
+            for (half_width, line) in [(dx, cy - dy), (dx, cy + dy), (dy, cy - dx), (dy, cy + dx)] {
+                self.plot_span_where(cx - half_width, cx + half_width, line, color, |x| {
+                    sector.contains(x - cx, line - cy)
+                });
+            }
+        });
+        self
+    }
+
+    /// Circle arc going clockwise from `start_angle` to `end_angle`
+    pub fn draw_arc_outline(
+        &mut self,
+        cx: i32,
+        cy: i32,
+        radius: u16,
+        start_angle: i32,
+        end_angle: i32,
+        color: Color,
+    ) -> &mut Self {
+        let sector = Sector::new(start_angle, end_angle);
+        if sector.is_empty() {
+            return self;
+        }
+        let (cx, cy) = (cx as i64, cy as i64);
+        circle_octant(radius, |x, y| {
+            for (dx, dy) in [
+                (x, y),
+                (y, x),
+                (-y, x),
+                (-x, y),
+                (-x, -y),
+                (-y, -x),
+                (y, -x),
+                (x, -y),
+            ] {
+                if sector.contains(dx, dy) {
+                    self.plot(cx + dx, cy + dy, color);
+                }
+            }
+        });
+        self
+    }
+
+    pub fn draw_triangle(&mut self, vertices: [(i32, i32); 3], color: Color) -> &mut Self {
+        self.draw_polygon(&vertices, color)
+    }
+
+    pub fn draw_triangle_outline(&mut self, vertices: [(i32, i32); 3], color: Color) -> &mut Self {
+        self.draw_polygon_outline(&vertices, color)
+    }
+
+    /// Filled polygon, following the even-odd rule for self-intersecting ones
+    pub fn draw_polygon(&mut self, vertices: &[(i32, i32)], color: Color) -> &mut Self {
+        let Some(&(first_x, first_y)) = vertices.first() else {
+            return self;
+        };
+        let (mut x_min, mut y_min, mut x_max, mut y_max) = (first_x, first_y, first_x, first_y);
+        for &(x, y) in vertices {
+            (x_min, x_max) = (min(x_min, x), max(x_max, x));
+            (y_min, y_max) = (min(y_min, y), max(y_max, y));
+        }
+        let x_range = max(x_min as i64, 0)..=min(x_max as i64, WIDTH as i64 - 1);
+        let y_range = max(y_min as i64, 0)..=min(y_max as i64, HEIGHT as i64 - 1);
+        for y in y_range {
+            for x in x_range.clone() {
+                if polygon_contains(vertices, x as i128, y as i128) {
+                    self.0[y as usize][x as usize] = color;
+                }
+            }
+        }
+        // The pixels on the edges are not always reached by the scan
+        self.draw_polygon_outline(vertices, color)
+    }
+
+    /// Closed outline, the last vertex being joined to the first one
+    pub fn draw_polygon_outline(&mut self, vertices: &[(i32, i32)], color: Color) -> &mut Self {
+        for (idx, &(x0, y0)) in vertices.iter().enumerate() {
+            let (x1, y1) = vertices[(idx + 1) % vertices.len()];
+            self.draw_line(x0, y0, x1, y1, color);
+        }
+        self
+    }
+}
+
+/// Centers of the corner circles of a rounded rectangle, and the fitted radius
+fn rounded_bounds(
+    x: i32,
+    y: i32,
+    width: u32,
+    height: u32,
+    radius: u16,
+) -> Option<(i64, i64, i64, i64, i64)> {
+    if width == 0 || height == 0 {
+        return None;
+    }
+    let (width, height) = (width as i64, height as i64);
+    let radius = min(radius as i64, (min(width, height) - 1) / 2);
+    let (left, top) = (x as i64 + radius, y as i64 + radius);
+    let (right, bottom) = (
+        x as i64 + width - 1 - radius,
+        y as i64 + height - 1 - radius,
+    );
+    Some((left, top, right, bottom, radius))
+}
+
+/// Even-odd test, counting the edges crossed by a ray going right from the pixel
+fn polygon_contains(vertices: &[(i32, i32)], x: i128, y: i128) -> bool {
+    let mut inside = false;
+    for (idx, &(x0, y0)) in vertices.iter().enumerate() {
+        let (x1, y1) = vertices[(idx + 1) % vertices.len()];
+        let (x0, y0, x1, y1) = (x0 as i128, y0 as i128, x1 as i128, y1 as i128);
+        if (y0 <= y) == (y1 <= y) {
+            continue;
+        }
+        // Crossing on the right of x, without dividing
+        let crossing_on_right = if y1 > y0 {
+            (x - x0) * (y1 - y0) < (y - y0) * (x1 - x0)
+        } else {
+            (x - x0) * (y1 - y0) > (y - y0) * (x1 - x0)
+        };
+        if crossing_on_right {
+            inside = !inside;
+        }
+    }
+    inside
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lit_pixels<const W: usize, const H: usize>(canvas: &Canvas<W, H>) -> usize {
+        canvas
+            .0
+            .iter()
+            .flatten()
+            .filter(|&&c| c != Color::BLACK)
+            .count()
+    }
+
+    #[test]
+    fn sine_and_cosine() {
+        assert_eq!(sin_deg(30), 8192);
+        assert_eq!(sin_deg(-90), -16384);
+        assert_eq!(sin_deg(210), -8192);
+        assert_eq!(cos_deg(180), -16384);
+        assert_eq!(cos_deg(i32::MAX), cos_deg(i32::MAX % 360));
+    }
+
+    #[test]
+    fn sectors() {
+        let quarter = Sector::new(-90, 0);
+        assert!(quarter.contains(3, -3));
+        assert!(quarter.contains(5, 0));
+        assert!(!quarter.contains(3, 3));
+        assert!(!quarter.contains(-3, -3));
+        let wide = Sector::new(45, 315);
+        assert!(wide.contains(-5, 0));
+        assert!(!wide.contains(5, 0));
+        assert!(Sector::new(10, 10).is_empty());
+        assert!(Sector::new(10, 370).contains(5, -1));
+    }
+
+    #[test]
+    fn thick_lines_keep_their_thickness() {
+        let mut horizontal = Canvas::<8, 8>::new();
+        horizontal.draw_thick_line(0, 3, 7, 3, 4, Color::WHITE);
+        assert_eq!(lit_pixels(&horizontal), 8 * 4);
+        // A diagonal line needs longer spans to look as thick
+        let mut diagonal = Canvas::<8, 8>::new();
+        diagonal.draw_thick_line(0, 0, 7, 7, 2, Color::WHITE);
+        assert_eq!(lit_pixels(&diagonal), 8 * 3 - 2);
+        let mut empty = Canvas::<8, 8>::new();
+        empty.draw_thick_line(0, 0, 7, 7, 0, Color::WHITE);
+        assert_eq!(lit_pixels(&empty), 0);
+    }
+
+    #[test]
+    fn lines_are_symmetric() {
+        let mut forward = Canvas::<16, 16>::new();
+        forward.draw_line(1, 2, 14, 9, Color::WHITE);
+        let mut backward = Canvas::<16, 16>::new();
+        backward.draw_line(14, 9, 1, 2, Color::WHITE);
+        assert!(forward.0 == backward.0);
+    }
+
+    #[test]
+    fn filled_shapes_cover_their_outline() {
+        let mut filled = Canvas::<24, 24>::new();
+        filled
+            .draw_circle(6, 6, 5, Color::WHITE)
+            .draw_ellipse(16, 16, 7, 4, Color::WHITE)
+            .draw_rounded_rectangle(13, 1, 10, 8, 3, Color::WHITE)
+            .draw_triangle([(1, 22), (10, 13), (4, 15)], Color::WHITE);
+        let mut outlined = filled.clone();
+        outlined
+            .draw_circle_outline(6, 6, 5, Color::RED)
+            .draw_ellipse_outline(16, 16, 7, 4, Color::RED)
+            .draw_rounded_rectangle_outline(13, 1, 10, 8, 3, Color::RED)
+            .draw_triangle_outline([(1, 22), (10, 13), (4, 15)], Color::RED);
+        assert_eq!(lit_pixels(&filled), lit_pixels(&outlined));
+    }
+
+    #[test]
+    fn polygon_matches_rectangle() {
+        let mut polygon = Canvas::<12, 10>::new();
+        polygon.draw_polygon(&[(2, 1), (9, 1), (9, 7), (2, 7)], Color::GREEN);
+        let mut rectangle = Canvas::<12, 10>::new();
+        rectangle.draw_rectangle(2, 1, 8, 7, Color::GREEN);
+        assert!(polygon.0 == rectangle.0);
+        let mut outline = Canvas::<12, 10>::new();
+        outline.draw_rectangle_outline(2, 1, 8, 7, Color::GREEN);
+        polygon.draw_rectangle(3, 2, 6, 5, Color::BLACK);
+        assert!(polygon.0 == outline.0);
+    }
+
+    #[test]
+    fn far_away_shapes_are_clipped() {
+        let mut canvas = Canvas::<8, 8>::new();
+        canvas
+            .draw_line(i32::MIN, i32::MIN, i32::MAX, i32::MAX, Color::WHITE)
+            .draw_thick_line(i32::MAX, 0, i32::MIN, 0, u16::MAX, Color::WHITE)
+            .draw_ellipse(i32::MAX, i32::MIN, u16::MAX, u16::MAX, Color::WHITE)
+            .draw_rounded_rectangle(i32::MIN, 0, u32::MAX, 4, u16::MAX, Color::WHITE)
+            .draw_polygon(&[], Color::WHITE)
+            .draw_polygon(&[(i32::MIN, i32::MIN)], Color::WHITE);
+        assert_eq!(lit_pixels(&canvas), 8 * 8);
+        canvas.clear().draw_polygon(&[(-3, 4)], Color::WHITE);
+        assert_eq!(lit_pixels(&canvas), 0);
+    }
+}
@@ -0,0 +1,42 @@
+/*
+MIT License
+
+Copyright (c) 2026 Vincent Hiribarren
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Fixtures shared by the tests of the lib and of the simulator.
+
+use crate::canvas::{Canvas, Color};
+
+/// Red growing along x and green along y, from 0, blue being the wrapped x * y
+pub fn gradient_canvas<const WIDTH: usize, const HEIGHT: usize>() -> Canvas<WIDTH, HEIGHT> {
+    let mut canvas = Canvas::new();
+    for (y, row) in canvas.0.iter_mut().enumerate() {
+        for (x, color) in row.iter_mut().enumerate() {
+            *color = Color::new(
+                (x * 256 / WIDTH) as u8,
+                (y * 256 / HEIGHT) as u8,
+                (x * y) as u8,
+            );
+        }
+    }
+    canvas
+}
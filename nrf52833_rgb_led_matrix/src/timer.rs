@@ -1,10 +1,38 @@
+use nrf52833_hal::pac::Interrupt;
+use nrf52833_hal::rtc::{self, Rtc, RtcCompareReg, RtcInterrupt};
 use nrf52833_hal::timer::Instance;
 
-pub struct Timer16Mhz<T>(T);
+/// Timer driving the scan of a [`ScheduledLedMatrix`](crate::scheduler::ScheduledLedMatrix):
+/// a single shot compare event, plus an optional earlier blanking event, both on `INTERRUPT`.
+pub trait ScanTimer {
+    const INTERRUPT: Interrupt;
+    /// Longest delay which can be started
+    const MAX_PERIOD_MICROSEC: u32;
+    /// Shortest BCM base period, lasting at least the shortest delay which can be started
+    const MIN_PERIOD_MICROSEC: u32;
+
+    fn microsec_to_ticks(microsec: u32) -> u32;
+    fn start(&mut self, ticks: u32);
+    fn enable_interrupt(&mut self);
+    fn disable_interrupt(&mut self);
+    /// Also trigger the interrupt `ticks` after the next start, before the main compare event.
+    fn enable_blanking(&mut self, ticks: u32);
+    fn disable_blanking(&mut self);
+    /// Return true, and disarm it, if the interrupt was triggered by the blanking event.
+    fn take_blanking_event(&mut self) -> bool;
+}
+
+pub struct Timer16Mhz<T> {
+    timer: T,
+    blanking_armed: bool,
+}
 
 impl<T: Instance> Timer16Mhz<T> {
     ///  0 -> 16 MHz, 1 -> 8 MHz, ... 4 -> 1 MHz
-    const PRESCALER: u8 = 4;
+    const PRESCALER: u8 = 0;
+    pub const TICKS_PER_MICROSEC: u32 = 16 >> Self::PRESCALER;
+    /// Compare register for the blanking event, which ends a slot before the main compare event
+    const BLANKING_CC: usize = 2;
 
     pub fn new(timer: T) -> Self {
         timer
@@ -16,33 +44,197 @@ impl<T: Instance> Timer16Mhz<T> {
             .prescaler
             .write(|w| unsafe { w.prescaler().bits(Self::PRESCALER) });
         timer.as_timer0().bitmode.write(|w| w.bitmode()._32bit());
-        Timer16Mhz(timer)
+        Timer16Mhz {
+            timer,
+            blanking_armed: false,
+        }
     }
 
     pub fn start(&mut self, cycles: u32) {
-        self.0.timer_start(cycles);
+        self.timer.timer_start(cycles);
+    }
+
+    pub fn start_microsec(&mut self, microsec: u32) {
+        self.start(microsec * Self::TICKS_PER_MICROSEC);
     }
 
     pub fn enable_interrupt(&mut self) {
-        self.0
+        self.timer
             .as_timer0()
             .intenset
             .modify(|_, w| w.compare0().set());
     }
 
     pub fn disable_interrupt(&self) {
-        self.0
+        self.timer
             .as_timer0()
             .intenclr
             .modify(|_, w| w.compare0().clear());
     }
 
+    /// Also trigger the interrupt `cycles` after the next start, before the main compare event.
+    pub fn enable_blanking(&mut self, cycles: u32) {
+        let timer = self.timer.as_timer0();
+        timer.cc[Self::BLANKING_CC].write(|w| unsafe { w.bits(cycles) });
+        timer.events_compare[Self::BLANKING_CC].reset();
+        timer.intenset.modify(|_, w| w.compare2().set());
+        self.blanking_armed = true;
+    }
+
+    pub fn disable_blanking(&mut self) {
+        self.timer
+            .as_timer0()
+            .intenclr
+            .modify(|_, w| w.compare2().clear());
+        self.blanking_armed = false;
+    }
+
+    /// Return true, and disarm it, if the interrupt was triggered by the blanking event.
+    pub fn take_blanking_event(&mut self) -> bool {
+        let triggered = self.blanking_armed
+            && self.timer.as_timer0().events_compare[Self::BLANKING_CC]
+                .read()
+                .bits()
+                != 0;
+        if triggered {
+            self.timer.as_timer0().events_compare[Self::BLANKING_CC].reset();
+            self.disable_blanking();
+        }
+        triggered
+    }
+
+    pub fn release(self) -> T {
+        self.disable_interrupt();
+        self.timer
+    }
+
     pub fn read(&self) -> u32 {
-        self.0.as_timer0().tasks_capture[1].write(|w| unsafe { w.bits(1) });
-        self.0.as_timer0().cc[1].read().bits()
+        self.timer.as_timer0().tasks_capture[1].write(|w| unsafe { w.bits(1) });
+        self.timer.as_timer0().cc[1].read().bits()
     }
 
     pub fn delay_us(&self, _: u32) {
         todo!()
     }
 }
+
+impl<T: Instance> ScanTimer for Timer16Mhz<T> {
+    const INTERRUPT: Interrupt = T::INTERRUPT;
+    const MAX_PERIOD_MICROSEC: u32 = u32::MAX / Self::TICKS_PER_MICROSEC;
+    const MIN_PERIOD_MICROSEC: u32 = 1;
+
+    fn microsec_to_ticks(microsec: u32) -> u32 {
+        microsec * Self::TICKS_PER_MICROSEC
+    }
+
+    fn start(&mut self, ticks: u32) {
+        Timer16Mhz::start(self, ticks);
+    }
+
+    fn enable_interrupt(&mut self) {
+        Timer16Mhz::enable_interrupt(self);
+    }
+
+    fn disable_interrupt(&mut self) {
+        Timer16Mhz::disable_interrupt(self);
+    }
+
+    fn enable_blanking(&mut self, ticks: u32) {
+        Timer16Mhz::enable_blanking(self, ticks);
+    }
+
+    fn disable_blanking(&mut self) {
+        Timer16Mhz::disable_blanking(self);
+    }
+
+    fn take_blanking_event(&mut self) -> bool {
+        Timer16Mhz::take_blanking_event(self)
+    }
+}
+
+/// Scan driven by a RTC, to leave all the TIMER peripherals to the application.
+///
+/// The LFCLK must be started. With a 32768 Hz resolution, a tick lasts about
+/// 30 µs: the BCM base period is rounded down to it, and lasts at least 2 ticks,
+/// which lowers the refresh rate.
+pub struct RtcScanTimer<R: rtc::Instance> {
+    rtc: Rtc<R>,
+    blanking_armed: bool,
+}
+
+impl<R: rtc::Instance> RtcScanTimer<R> {
+    pub const TICKS_PER_SEC: u32 = 32_768;
+    /// The counter is on 24 bits
+    const MAX_COMPARE_VALUE: u32 = 0x00FF_FFFF;
+    /// A compare value of 0 or 1 right after clearing the counter may never match.
+    const MIN_COMPARE_VALUE: u32 = 2;
+
+    pub fn new(rtc: R) -> Self {
+        RtcScanTimer {
+            // A prescaler of 0 is always valid
+            rtc: Rtc::new(rtc, 0).unwrap(),
+            blanking_armed: false,
+        }
+    }
+
+    pub fn release(self) -> R {
+        self.rtc.release()
+    }
+
+    fn compare_value(ticks: u32) -> u32 {
+        ticks.clamp(Self::MIN_COMPARE_VALUE, Self::MAX_COMPARE_VALUE)
+    }
+}
+
+impl<R: rtc::Instance> ScanTimer for RtcScanTimer<R> {
+    const INTERRUPT: Interrupt = R::INTERRUPT;
+    const MAX_PERIOD_MICROSEC: u32 =
+        (Self::MAX_COMPARE_VALUE as u64 * 1_000_000 / Self::TICKS_PER_SEC as u64) as u32;
+    const MIN_PERIOD_MICROSEC: u32 =
+        (Self::MIN_COMPARE_VALUE as u64 * 1_000_000).div_ceil(Self::TICKS_PER_SEC as u64) as u32;
+
+    fn microsec_to_ticks(microsec: u32) -> u32 {
+        (microsec as u64 * Self::TICKS_PER_SEC as u64 / 1_000_000) as u32
+    }
+
+    fn start(&mut self, ticks: u32) {
+        self.rtc.disable_counter();
+        self.rtc.clear_counter();
+        self.rtc
+            .set_compare(RtcCompareReg::Compare0, Self::compare_value(ticks))
+            .unwrap();
+        self.rtc.reset_event(RtcInterrupt::Compare0);
+        self.rtc.enable_counter();
+    }
+
+    fn enable_interrupt(&mut self) {
+        self.rtc.enable_interrupt(RtcInterrupt::Compare0, None);
+    }
+
+    fn disable_interrupt(&mut self) {
+        self.rtc.disable_interrupt(RtcInterrupt::Compare0, None);
+    }
+
+    fn enable_blanking(&mut self, ticks: u32) {
+        self.rtc
+            .set_compare(RtcCompareReg::Compare1, Self::compare_value(ticks))
+            .unwrap();
+        self.rtc.reset_event(RtcInterrupt::Compare1);
+        self.rtc.enable_interrupt(RtcInterrupt::Compare1, None);
+        self.blanking_armed = true;
+    }
+
+    fn disable_blanking(&mut self) {
+        self.rtc.disable_interrupt(RtcInterrupt::Compare1, None);
+        self.blanking_armed = false;
+    }
+
+    fn take_blanking_event(&mut self) -> bool {
+        let triggered = self.blanking_armed && self.rtc.is_event_triggered(RtcInterrupt::Compare1);
+        if triggered {
+            self.rtc.reset_event(RtcInterrupt::Compare1);
+            self.disable_blanking();
+        }
+        triggered
+    }
+}
@@ -0,0 +1,259 @@
+/*
+MIT License
+
+Copyright (c) 2026 Vincent Hiribarren
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+// Drawing of the canvas primitives and of the fonts, compared with the
+// references of `tests/golden`. After an intended change, write the new
+// references with:
+//
+//     BLESS=1 cargo test --no-default-features --test golden --target <host>
+//
+// and check their diff before committing them.
+
+mod snapshot;
+
+use nrf52833_rgb_led_matrix::canvas::{BlendMode, Canvas, Color, Stencil, Stencil5x7, TextOptions};
+use nrf52833_rgb_led_matrix::fonts::{Font14x32, Font5x7, Font8x16};
+use snapshot::{assert_golden, canvas_mismatches, canvas_to_text};
+
+fn text_options(color: Color, interspace: usize) -> TextOptions {
+    TextOptions { interspace, color }
+}
+
+#[test]
+fn font5x7_printable_ascii() {
+    let mut canvas = Canvas::<60, 80>::new();
+    let chars: Vec<char> = (' '..='~').collect();
+    for (row, line) in chars.chunks(10).enumerate() {
+        let line: String = line.iter().collect();
+        canvas.draw_text(0, row * 8, &line, Font5x7, Default::default());
+    }
+    assert_golden("font5x7_printable_ascii", &canvas);
+}
+
+#[test]
+fn font8x16_glyphs() {
+    let mut canvas = Canvas::<54, 33>::new();
+    canvas
+        .draw_text(0, 0, "012345", Font8x16, Default::default())
+        .draw_text(0, 17, "6789 x", Font8x16, Default::default());
+    assert_golden("font8x16_glyphs", &canvas);
+}
+
+#[test]
+fn font14x32_glyphs() {
+    let mut canvas = Canvas::<75, 32>::new();
+    canvas.draw_text(0, 0, " 014x", Font14x32, Default::default());
+    assert_golden("font14x32_glyphs", &canvas);
+}
+
+#[test]
+fn draw_number() {
+    let mut canvas = Canvas::<64, 40>::new();
+    canvas
+        .draw_number(0, 0, 0, Font5x7, Default::default())
+        .draw_number(10, 0, 7, Font5x7, text_options(Color::RED, 1))
+        .draw_number(20, 0, 1207, Font5x7, text_options(Color::GREEN, 0))
+        .draw_number(0, 8, u32::MAX, Font5x7, text_options(Color::CYAN, 1))
+        .draw_number(0, 16, 42, Font8x16, text_options(Color::YELLOW, 2))
+        .draw_number(20, 16, 1, Font14x32, text_options(Color::MAGENTA, 1));
+    assert_golden("draw_number", &canvas);
+}
+
+#[test]
+fn draw_text_colors_and_clipping() {
+    let mut canvas = Canvas::<32, 16>::new();
+    canvas
+        .draw_text(0, 0, "Hi!", Font5x7, text_options(Color::BLUE, 0))
+        .draw_text(
+            18,
+            0,
+            "OK",
+            Font5x7,
+            text_options(Color::new(255, 128, 0), 3),
+        )
+        // Cut by the right and the bottom borders
+        .draw_text(20, 11, "WXYZ", Font5x7, Default::default());
+    assert_golden("draw_text_colors_and_clipping", &canvas);
+}
+
+#[test]
+fn draw_stencil_and_char() {
+    const ARROW: Stencil5x7 = Stencil([
+        [0, 0, 1, 0, 0],
+        [0, 1, 1, 1, 0],
+        [1, 0, 1, 0, 1],
+        [0, 0, 1, 0, 0],
+        [0, 0, 1, 0, 0],
+        [0, 0, 1, 0, 0],
+        [0, 0, 1, 0, 0],
+    ]);
+    let mut canvas = Canvas::<16, 10>::with_background_color(Color::new(0, 0, 64));
+    canvas
+        .draw_stencil(1, 1, &ARROW, Color::GREEN)
+        .draw_stencil(13, 5, &ARROW, Color::RED)
+        .draw_char(7, 1, '$', Color::WHITE, Font5x7);
+    assert_golden("draw_stencil_and_char", &canvas);
+}
+
+#[test]
+fn primitives() {
+    let mut sprite = Canvas::<4, 4>::new();
+    sprite
+        .draw_rectangle(1, 1, 2, 2, Color::MAGENTA)
+        .draw_pixel(0, 0, Color::WHITE);
+    let mut canvas = Canvas::<24, 12>::with_background_color(Color::new(32, 32, 32));
+    canvas
+        .draw_rectangle(2, 2, 6, 4, Color::RED)
+        .draw_rectangle(20, 8, 10, 10, Color::GREEN)
+        .draw_pixel(23, 0, Color::YELLOW)
+        .draw_pixel(24, 0, Color::YELLOW)
+        .draw_canvas(4, 4, &sprite, BlendMode::TransparentBlack)
+        .draw_canvas(12, 4, &sprite, BlendMode::Replace)
+        .draw_canvas(22, 1, &sprite, BlendMode::Replace);
+    assert_golden("primitives", &canvas);
+}
+
+#[test]
+fn lines() {
+    let mut canvas = Canvas::<24, 16>::new();
+    canvas
+        .draw_line(0, 0, 23, 0, Color::WHITE)
+        .draw_line(0, 2, 23, 9, Color::RED)
+        .draw_line(2, 15, 8, 2, Color::GREEN)
+        .draw_line(20, 1, 20, 1, Color::YELLOW)
+        .draw_thick_line(10, 14, 22, 4, 3, Color::CYAN)
+        .draw_thick_line(14, 15, 23, 15, 2, Color::BLUE);
+    assert_golden("lines", &canvas);
+}
+
+#[test]
+fn circles_and_ellipses() {
+    let mut canvas = Canvas::<32, 16>::new();
+    canvas
+        .draw_circle(4, 4, 3, Color::RED)
+        .draw_circle_outline(4, 11, 4, Color::WHITE)
+        .draw_circle_outline(14, 7, 0, Color::YELLOW)
+        .draw_ellipse(20, 4, 6, 2, Color::GREEN)
+        .draw_ellipse_outline(22, 11, 8, 3, Color::CYAN)
+        .draw_ellipse_outline(13, 12, 0, 3, Color::MAGENTA);
+    assert_golden("circles_and_ellipses", &canvas);
+}
+
+#[test]
+fn arcs() {
+    let mut canvas = Canvas::<32, 12>::new();
+    canvas
+        .draw_arc(5, 5, 5, -90, 0, Color::RED)
+        .draw_arc(16, 5, 5, 45, 315, Color::YELLOW)
+        .draw_arc_outline(27, 5, 4, 180, 360, Color::WHITE)
+        .draw_arc_outline(27, 6, 4, 0, 90, Color::CYAN);
+    assert_golden("arcs", &canvas);
+}
+
+#[test]
+fn rectangles() {
+    let mut canvas = Canvas::<32, 12>::new();
+    canvas
+        .draw_rectangle_outline(0, 0, 8, 6, Color::WHITE)
+        .draw_rectangle_outline(2, 8, 1, 1, Color::RED)
+        .draw_rounded_rectangle(10, 0, 10, 7, 2, Color::GREEN)
+        .draw_rounded_rectangle_outline(22, 0, 10, 12, 3, Color::CYAN)
+        .draw_rounded_rectangle_outline(10, 8, 8, 4, 9, Color::YELLOW);
+    assert_golden("rectangles", &canvas);
+}
+
+#[test]
+fn polygons() {
+    let mut canvas = Canvas::<32, 16>::new();
+    canvas
+        .draw_triangle([(1, 14), (7, 1), (12, 10)], Color::RED)
+        .draw_triangle_outline([(1, 14), (7, 1), (12, 10)], Color::WHITE)
+        .draw_polygon(
+            &[(16, 1), (22, 1), (30, 7), (22, 14), (18, 9)],
+            Color::GREEN,
+        )
+        // Star, whose center is left empty by the even-odd rule
+        .draw_polygon(
+            &[(24, 4), (28, 14), (20, 8), (30, 8), (20, 14)],
+            Color::BLUE,
+        );
+    assert_golden("polygons", &canvas);
+}
+
+#[test]
+fn shapes_are_clipped() {
+    let mut canvas = Canvas::<16, 8>::new();
+    canvas
+        .draw_thick_line(-10, -3, 30, 12, 2, Color::WHITE)
+        .draw_line(i32::MIN, 7, i32::MAX, 7, Color::RED)
+        .draw_circle(-2, 3, 4, Color::GREEN)
+        .draw_ellipse_outline(15, 0, 6, 3, Color::CYAN)
+        .draw_arc(8, 9, 3, 180, 360, Color::YELLOW)
+        .draw_rounded_rectangle_outline(12, 5, 10, 10, 2, Color::MAGENTA)
+        .draw_polygon(&[(-100, -100), (3, -100), (3, 1)], Color::BLUE);
+    assert_golden("shapes_are_clipped", &canvas);
+}
+
+#[test]
+fn apply_luminosity() {
+    let mut canvas = Canvas::<8, 3>::new();
+    for (x, color) in [
+        Color::WHITE,
+        Color::RED,
+        Color::new(200, 100, 50),
+        Color::CYAN,
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        canvas.draw_rectangle(x * 2, 0, 2, 3, color);
+    }
+    canvas.apply_luminosity(100);
+    assert_golden("apply_luminosity", &canvas);
+}
+
+#[test]
+fn reference_format() {
+    let mut canvas = Canvas::<5, 2>::new();
+    canvas
+        .draw_pixel(1, 0, Color::WHITE)
+        .draw_pixel(3, 0, Color::WHITE)
+        .draw_rectangle(0, 1, 2, 1, Color::new(1, 2, 255));
+    assert_eq!(
+        canvas_to_text(&canvas),
+        "5x2\n. 000000\n# ffffff\nA 0102ff\n=\n.#.#.\nAA...\n"
+    );
+}
+
+#[test]
+fn mismatches_are_reported() {
+    let expected = Canvas::<3, 2>::new();
+    assert_eq!(canvas_mismatches(&expected, &expected), None);
+    let mut actual = expected.clone();
+    actual.draw_pixel(2, 1, Color::RED);
+    assert_eq!(
+        canvas_mismatches(&expected, &actual).unwrap(),
+        "1 mismatched pixels:\n...\n..X\n  (2, 1): expected 000000, got ff0000\n"
+    );
+}
@@ -0,0 +1,238 @@
+/*
+MIT License
+
+Copyright (c) 2026 Vincent Hiribarren
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Comparison of canvases with reference images, stored as text art in `tests/golden`.
+//!
+//! A reference starts with the size of the canvas, then the palette, one color
+//! per line, and the pixels, one character per pixel:
+//!
+//! ```text
+//! 5x2
+//! . 000000
+//! # ffffff
+//! =
+//! .#.#.
+//! ##...
+//! ```
+//!
+//! With the `BLESS` environment variable set, the references are written from
+//! the canvases instead of being compared.
+
+use std::fmt::Write;
+use std::path::PathBuf;
+
+use nrf52833_rgb_led_matrix::canvas::Canvas;
+
+/// Characters of the colors, in their order of appearance, black being always `.`
+const PALETTE_CHARS: &str = "#ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789@%&*+";
+/// Maximum number of listed mismatched pixels
+const MAX_LISTED_MISMATCHES: usize = 20;
+
+/// Colors of the pixels, row after row
+struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<[u8; 3]>,
+}
+
+impl Image {
+    fn from_canvas<const WIDTH: usize, const HEIGHT: usize>(
+        canvas: &Canvas<WIDTH, HEIGHT>,
+    ) -> Self {
+        Image {
+            width: WIDTH,
+            height: HEIGHT,
+            pixels: canvas
+                .0
+                .iter()
+                .flatten()
+                .map(|color| [color.r(), color.g(), color.b()])
+                .collect(),
+        }
+    }
+
+    fn pixel(&self, x: usize, y: usize) -> [u8; 3] {
+        self.pixels[y * self.width + x]
+    }
+
+    fn to_text(&self) -> String {
+        let mut palette: Vec<[u8; 3]> = vec![[0, 0, 0]];
+        for pixel in &self.pixels {
+            if !palette.contains(pixel) {
+                palette.push(*pixel);
+            }
+        }
+        assert!(
+            palette.len() <= PALETTE_CHARS.len() + 1,
+            "Too many colors for a reference image: {}",
+            palette.len()
+        );
+        let palette_char = |color: &[u8; 3]| match palette.iter().position(|c| c == color) {
+            Some(0) => '.',
+            Some(idx) => PALETTE_CHARS.as_bytes()[idx - 1] as char,
+            None => unreachable!(),
+        };
+
+        let mut text = format!("{}x{}\n", self.width, self.height);
+        for color in &palette {
+            writeln!(text, "{} {}", palette_char(color), hex(color)).unwrap();
+        }
+        text.push_str("=\n");
+        for row in self.pixels.chunks(self.width) {
+            text.extend(row.iter().map(palette_char));
+            text.push('\n');
+        }
+        text
+    }
+
+    fn parse(text: &str) -> Result<Self, String> {
+        let mut lines = text.lines();
+        let size = lines.next().ok_or("Empty reference")?;
+        let (width, height) = size
+            .split_once('x')
+            .and_then(|(w, h)| Some((w.parse().ok()?, h.parse().ok()?)))
+            .ok_or_else(|| format!("Invalid size: {size}"))?;
+        let mut palette = Vec::new();
+        for line in lines.by_ref().take_while(|&line| line != "=") {
+            let (symbol, color) = line
+                .split_once(' ')
+                .ok_or_else(|| format!("Invalid palette line: {line}"))?;
+            let color = u32::from_str_radix(color, 16)
+                .map_err(|_| format!("Invalid palette color: {line}"))?;
+            let [_, r, g, b] = color.to_be_bytes();
+            palette.push((symbol.chars().next().unwrap_or(' '), [r, g, b]));
+        }
+        let mut pixels = Vec::with_capacity(width * height);
+        for line in lines {
+            if line.chars().count() != width {
+                return Err(format!(
+                    "Row of {} pixels instead of {width}",
+                    line.chars().count()
+                ));
+            }
+            for symbol in line.chars() {
+                let (_, color) = palette
+                    .iter()
+                    .find(|(s, _)| *s == symbol)
+                    .ok_or_else(|| format!("Character not in the palette: {symbol}"))?;
+                pixels.push(*color);
+            }
+        }
+        if pixels.len() != width * height {
+            return Err(format!("{} rows instead of {height}", pixels.len() / width));
+        }
+        Ok(Image {
+            width,
+            height,
+            pixels,
+        })
+    }
+}
+
+fn hex(color: &[u8; 3]) -> String {
+    format!("{:02x}{:02x}{:02x}", color[0], color[1], color[2])
+}
+
+/// Grid of the pixels, `X` for the mismatched ones, and the list of the first ones
+fn mismatch_report(expected: &Image, actual: &Image) -> Option<String> {
+    let mut grid = String::new();
+    let mut listed = String::new();
+    let mut mismatch_count = 0;
+    for y in 0..actual.height {
+        for x in 0..actual.width {
+            let (expected_pixel, actual_pixel) = (expected.pixel(x, y), actual.pixel(x, y));
+            if expected_pixel == actual_pixel {
+                grid.push('.');
+                continue;
+            }
+            grid.push('X');
+            mismatch_count += 1;
+            if mismatch_count <= MAX_LISTED_MISMATCHES {
+                writeln!(
+                    listed,
+                    "  ({x}, {y}): expected {}, got {}",
+                    hex(&expected_pixel),
+                    hex(&actual_pixel)
+                )
+                .unwrap();
+            }
+        }
+        grid.push('\n');
+    }
+    (mismatch_count > 0).then(|| format!("{mismatch_count} mismatched pixels:\n{grid}{listed}"))
+}
+
+/// Compare `canvas` with the reference `tests/golden/<name>.txt`, or write the
+/// reference with `BLESS` set.
+pub fn assert_golden<const WIDTH: usize, const HEIGHT: usize>(
+    name: &str,
+    canvas: &Canvas<WIDTH, HEIGHT>,
+) {
+    let path: PathBuf = [
+        env!("CARGO_MANIFEST_DIR"),
+        "tests",
+        "golden",
+        &format!("{name}.txt"),
+    ]
+    .iter()
+    .collect();
+    let actual = Image::from_canvas(canvas);
+    if std::env::var_os("BLESS").is_some() {
+        std::fs::write(&path, actual.to_text()).unwrap();
+        return;
+    }
+    let bless_hint = "run the tests with BLESS=1 to write the reference";
+    let text = std::fs::read_to_string(&path)
+        .unwrap_or_else(|err| panic!("Cannot read {}: {err}, {bless_hint}", path.display()));
+    let expected = Image::parse(&text)
+        .unwrap_or_else(|err| panic!("Invalid reference {}: {err}", path.display()));
+    assert!(
+        (expected.width, expected.height) == (WIDTH, HEIGHT),
+        "{name}: reference of {}x{} pixels, canvas of {WIDTH}x{HEIGHT} pixels",
+        expected.width,
+        expected.height
+    );
+    if let Some(report) = mismatch_report(&expected, &actual) {
+        panic!(
+            "{name} differs from {}, {bless_hint} if expected.\n{report}\nActual image:\n{}",
+            path.display(),
+            actual.to_text()
+        );
+    }
+}
+
+/// Text of `canvas`, as written in the references
+pub fn canvas_to_text<const WIDTH: usize, const HEIGHT: usize>(
+    canvas: &Canvas<WIDTH, HEIGHT>,
+) -> String {
+    Image::from_canvas(canvas).to_text()
+}
+
+/// Report of the mismatched pixels of two canvases
+pub fn canvas_mismatches<const WIDTH: usize, const HEIGHT: usize>(
+    expected: &Canvas<WIDTH, HEIGHT>,
+    actual: &Canvas<WIDTH, HEIGHT>,
+) -> Option<String> {
+    mismatch_report(&Image::from_canvas(expected), &Image::from_canvas(actual))
+}